@@ -1,4 +1,7 @@
+use crate::export::{self, ExportFormat};
+use crate::pg::client::SslMode;
 use crate::tui::app::App;
+use crate::tui::theme::Theme;
 use anyhow::Result;
 
 #[derive(Debug)]
@@ -9,6 +12,19 @@ pub enum Action {
         top_n: u32,
         home_view: String,
         sort: String,
+        theme: Theme,
+        sslmode: String,
+        sslrootcert: Option<String>,
+        listen_channels: Vec<String>,
+        metrics_addr: Option<std::net::SocketAddr>,
+    },
+    Export {
+        dsn: String,
+        sslmode: String,
+        sslrootcert: Option<String>,
+        format: String,
+        iterations: u32,
+        interval_ms: u64,
     },
 }
 
@@ -21,10 +37,45 @@ impl Action {
                 top_n,
                 home_view,
                 sort,
+                theme,
+                sslmode,
+                sslrootcert,
+                listen_channels,
+                metrics_addr,
             } => {
-                let mut app = App::new(dsn, refresh_ms, top_n, &home_view, &sort);
+                let mut app = App::new(
+                    dsn,
+                    refresh_ms,
+                    top_n,
+                    &home_view,
+                    &sort,
+                    theme,
+                    &sslmode,
+                    sslrootcert,
+                    listen_channels,
+                    metrics_addr,
+                );
                 app.run()
             }
+            Action::Export {
+                dsn,
+                sslmode,
+                sslrootcert,
+                format,
+                iterations,
+                interval_ms,
+            } => {
+                let sslmode = SslMode::parse(&sslmode).unwrap_or(SslMode::Prefer);
+                let format = ExportFormat::parse(&format)?;
+                export::run(
+                    &dsn,
+                    sslmode,
+                    sslrootcert.as_deref(),
+                    format,
+                    iterations,
+                    interval_ms,
+                )
+            }
         }
     }
 }
@@ -78,6 +78,62 @@ pub fn new() -> Command {
                 .value_parser(["total_time", "mean_time", "calls", "longest_running"])
                 .default_value("longest_running"),
         )
+        .arg(
+            Arg::new("sslmode")
+                .long("sslmode")
+                .value_name("MODE")
+                .help("TLS mode for the Postgres connection")
+                .long_help("Controls whether and how pgmon encrypts the connection to Postgres. 'disable' never uses TLS, 'prefer' uses TLS when the server offers it and otherwise connects unencrypted, 'require' refuses to connect without TLS (neither verifies the server certificate), and 'verify-full' verifies the certificate chain and hostname against the system's root certificate store.")
+                .value_parser(["disable", "prefer", "require", "verify-full"])
+                .default_value("prefer"),
+        )
+        .arg(
+            Arg::new("sslrootcert")
+                .long("sslrootcert")
+                .value_name("PATH")
+                .help("Path to a PEM file with trusted root certificates")
+                .long_help("When combined with --sslmode=verify-full, adds the certificates in this PEM file to the trusted root store used to verify the server's certificate, in addition to the system roots."),
+        )
+        .arg(
+            Arg::new("export")
+                .long("export")
+                .value_name("FORMAT")
+                .help("Run headlessly and export snapshots instead of the TUI")
+                .long_help("Skip the interactive UI and write structured snapshots (activity/database/locks/io/statements) to stdout in the given format, one batch per --refresh-ms interval for --export-iterations samples. Intended for piping into a file for offline analysis or bulk-loading.")
+                .value_parser(["jsonl", "csv"]),
+        )
+        .arg(
+            Arg::new("export-iterations")
+                .long("export-iterations")
+                .value_name("N")
+                .help("Number of samples to capture with --export")
+                .long_help("Capture this many successive snapshots at the configured refresh interval before exiting. Only applies when --export is set.")
+                .value_parser(clap::value_parser!(u32).range(1..=100_000))
+                .default_value("1"),
+        )
+        .arg(
+            Arg::new("metrics-addr")
+                .long("metrics-addr")
+                .value_name("HOST:PORT")
+                .help("Expose collected stats as Prometheus metrics on this address")
+                .long_help("Start a lightweight HTTP server bound to HOST:PORT that serves the same cache-hit, commit/rollback, connection, and top-statement stats collected for the TUI in Prometheus text exposition format at /metrics, so the same DSN can feed dashboards and alerting. Per-query label cardinality is bounded by --top-n.")
+                .value_parser(clap::value_parser!(std::net::SocketAddr)),
+        )
+        .arg(
+            Arg::new("listen")
+                .long("listen")
+                .value_name("CHANNEL")
+                .help("Postgres NOTIFY channel to subscribe to (repeatable)")
+                .long_help("Subscribe to a Postgres NOTIFY channel via LISTEN and stream arriving notifications into the 'Events' tab. Pass multiple times to watch several channels (e.g. --listen deadlocks --listen app_events).")
+                .action(ArgAction::Append),
+        )
+        .arg(
+            Arg::new("config")
+                .long("config")
+                .value_name("PATH")
+                .help("TOML file with startup defaults")
+                .long_help("Load defaults for --home-view, --top-n, --refresh-ms, and the color theme from a TOML file (see [theme] for the overridable colors/thresholds). Any of those flags passed explicitly on the command line still wins over the file."),
+        )
         .arg(
             Arg::new("verbose")
                 .short('v')
@@ -120,6 +176,10 @@ mod tests {
             matches.get_one::<String>("home-view"),
             Some(&"activity".to_string())
         );
+        assert_eq!(
+            matches.get_one::<String>("sslmode"),
+            Some(&"prefer".to_string())
+        );
     }
 
     #[test]
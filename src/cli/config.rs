@@ -0,0 +1,25 @@
+use crate::tui::theme::Theme;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::path::Path;
+
+/// On-disk startup defaults loaded from `--config`, following bottom's boot-flag config
+/// pattern: every field here is a *default*, overridden by any of `--home-view`, `--top-n`,
+/// or `--refresh-ms` the user passes explicitly (see `cli::dispatch::handler`).
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub default_tab: Option<String>,
+    pub top_n: Option<u32>,
+    pub refresh_ms: Option<u64>,
+    pub theme: Theme,
+}
+
+impl Config {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read --config file: {}", path.display()))?;
+        toml::from_str(&text)
+            .with_context(|| format!("Failed to parse --config file: {}", path.display()))
+    }
+}
@@ -1,24 +1,119 @@
 use crate::cli::actions::Action;
+use crate::cli::config::Config;
+use anyhow::Result;
+use clap::parser::ValueSource;
 use clap::ArgMatches;
+use std::path::Path;
 
-pub fn handler(matches: &ArgMatches) -> Action {
+/// Resolves `id` to the flag's command-line value if the user passed it explicitly,
+/// otherwise to `config_value` if the config file set one, otherwise to clap's own
+/// `default_value` for `id`. This is the precedence `cli::config::Config`'s doc comment
+/// promises: CLI flag > config file > built-in default.
+fn explicit_or<T: Clone + Send + Sync + 'static>(
+    matches: &ArgMatches,
+    id: &str,
+    config_value: Option<T>,
+) -> T {
+    if matches.value_source(id) == Some(ValueSource::CommandLine) {
+        return matches
+            .get_one::<T>(id)
+            .cloned()
+            .expect("command-line value source implies a value");
+    }
+    config_value.unwrap_or_else(|| {
+        matches
+            .get_one::<T>(id)
+            .cloned()
+            .expect("clap default_value implies a value")
+    })
+}
+
+pub fn handler(matches: &ArgMatches) -> Result<Action> {
     let dsn = matches.get_one::<String>("dsn").expect("required").clone();
-    let refresh_ms = *matches.get_one::<u64>("refresh-ms").unwrap_or(&1000);
-    let top_n = *matches.get_one::<u32>("top-n").unwrap_or(&10);
-    let home_view = matches
-        .get_one::<String>("home-view")
-        .cloned()
-        .unwrap_or_else(|| "activity".into());
+
+    let config = matches
+        .get_one::<String>("config")
+        .map(|path| Config::load(Path::new(path)))
+        .transpose()?;
+
+    let refresh_ms = explicit_or(
+        matches,
+        "refresh-ms",
+        config.as_ref().and_then(|c| c.refresh_ms),
+    );
+    let top_n = explicit_or(matches, "top-n", config.as_ref().and_then(|c| c.top_n));
+    let home_view = explicit_or(
+        matches,
+        "home-view",
+        config.as_ref().and_then(|c| c.default_tab.clone()),
+    );
+    let theme = config.map(|c| c.theme).unwrap_or_default();
     let sort = matches
         .get_one::<String>("sort")
         .cloned()
         .unwrap_or_else(|| "longest_running".into());
+    let sslmode = matches
+        .get_one::<String>("sslmode")
+        .cloned()
+        .unwrap_or_else(|| "prefer".into());
+    let sslrootcert = matches.get_one::<String>("sslrootcert").cloned();
+    let listen_channels = matches
+        .get_many::<String>("listen")
+        .map(|vals| vals.cloned().collect())
+        .unwrap_or_default();
+    let metrics_addr = matches
+        .get_one::<std::net::SocketAddr>("metrics-addr")
+        .copied();
+
+    if let Some(format) = matches.get_one::<String>("export").cloned() {
+        let iterations = *matches.get_one::<u32>("export-iterations").unwrap_or(&1);
+        return Ok(Action::Export {
+            dsn,
+            sslmode,
+            sslrootcert,
+            format,
+            iterations,
+            interval_ms: refresh_ms,
+        });
+    }
 
-    Action::StartTui {
+    Ok(Action::StartTui {
         dsn,
         refresh_ms,
         top_n,
         home_view,
         sort,
+        theme,
+        sslmode,
+        sslrootcert,
+        listen_channels,
+        metrics_addr,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::commands;
+
+    #[test]
+    fn test_explicit_or_prefers_command_line_over_config() {
+        let cmd = commands::new();
+        let matches = cmd.get_matches_from(vec!["pgmon", "--dsn", "x", "--refresh-ms", "500"]);
+        assert_eq!(explicit_or(&matches, "refresh-ms", Some(2000u64)), 500);
+    }
+
+    #[test]
+    fn test_explicit_or_falls_back_to_config_value() {
+        let cmd = commands::new();
+        let matches = cmd.get_matches_from(vec!["pgmon", "--dsn", "x"]);
+        assert_eq!(explicit_or(&matches, "refresh-ms", Some(2000u64)), 2000);
+    }
+
+    #[test]
+    fn test_explicit_or_falls_back_to_clap_default() {
+        let cmd = commands::new();
+        let matches = cmd.get_matches_from(vec!["pgmon", "--dsn", "x"]);
+        assert_eq!(explicit_or::<u64>(&matches, "refresh-ms", None), 1000);
     }
 }
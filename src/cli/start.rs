@@ -7,6 +7,6 @@ pub fn run() -> Result<()> {
     let verbosity = matches.get_count("verbose");
     telemetry::init(verbosity as u8);
 
-    let action = dispatch::handler(&matches);
+    let action = dispatch::handler(&matches)?;
     action.execute()
 }
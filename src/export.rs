@@ -0,0 +1,143 @@
+use crate::pg::client::{PgClient, SslMode};
+use anyhow::{anyhow, Result};
+use std::io::Write;
+use std::thread;
+use std::time::Duration;
+
+/// Output encoding for headless `--export` snapshots.
+#[derive(Debug, Clone, Copy)]
+pub enum ExportFormat {
+    Jsonl,
+    Csv,
+}
+
+impl ExportFormat {
+    pub fn parse(s: &str) -> Result<Self> {
+        match s {
+            "jsonl" => Ok(Self::Jsonl),
+            "csv" => Ok(Self::Csv),
+            other => Err(anyhow!("unknown export format: {other}")),
+        }
+    }
+}
+
+/// Views captured by `--export`, in the order they're written each iteration.
+const VIEWS: &[&str] = &["activity", "database", "locks", "io", "statements"];
+
+/// Runs the same `PgClient` fetch functions used by the TUI, but headlessly: writes one
+/// record per row to stdout instead of drawing a frame, `iterations` times at
+/// `interval_ms` apart. Lets the refresh/parsing pipeline be exercised without a terminal.
+pub fn run(
+    dsn: &str,
+    sslmode: SslMode,
+    sslrootcert: Option<&str>,
+    format: ExportFormat,
+    iterations: u32,
+    interval_ms: u64,
+) -> Result<()> {
+    let mut client = PgClient::new(dsn, sslmode, sslrootcert)?;
+    let stdout = std::io::stdout();
+    let mut out = stdout.lock();
+
+    for i in 0..iterations {
+        let timestamp = chrono::Utc::now().to_rfc3339();
+        for &view in VIEWS {
+            for row in fetch_view(&mut client, view)? {
+                write_record(&mut out, format, &timestamp, view, &row)?;
+            }
+        }
+        if i + 1 < iterations {
+            thread::sleep(Duration::from_millis(interval_ms));
+        }
+    }
+
+    out.flush()?;
+    Ok(())
+}
+
+fn fetch_view(client: &mut PgClient, view: &str) -> Result<Vec<Vec<String>>> {
+    match view {
+        "activity" => client.fetch_active_queries(),
+        "database" => client.fetch_database_stats(),
+        "locks" => client.fetch_locks(),
+        "io" => client.fetch_io_stats(),
+        "statements" => client.fetch_statements(),
+        other => Err(anyhow!("unknown export view: {other}")),
+    }
+}
+
+fn write_record(
+    out: &mut impl Write,
+    format: ExportFormat,
+    timestamp: &str,
+    view: &str,
+    row: &[String],
+) -> Result<()> {
+    match format {
+        ExportFormat::Jsonl => {
+            let columns: Vec<String> = row.iter().map(|c| json_string(c)).collect();
+            writeln!(
+                out,
+                r#"{{"timestamp":"{timestamp}","view":"{view}","columns":[{}]}}"#,
+                columns.join(",")
+            )?;
+        }
+        ExportFormat::Csv => {
+            let mut fields = vec![timestamp.to_string(), view.to_string()];
+            fields.extend(row.iter().map(|c| csv_field(c)));
+            writeln!(out, "{}", fields.join(","))?;
+        }
+    }
+    Ok(())
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains(',') || s.contains('"') || s.contains('\n') {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_string_escapes_control_and_special_chars() {
+        assert_eq!(json_string("plain"), r#""plain""#);
+        assert_eq!(json_string(r#"a"b"#), r#""a\"b""#);
+        assert_eq!(json_string(r"a\b"), r#""a\\b""#);
+        assert_eq!(json_string("a\nb"), r#""a\nb""#);
+        assert_eq!(json_string("a\rb"), r#""a\rb""#);
+        assert_eq!(json_string("a\tb"), r#""a\tb""#);
+        assert_eq!(json_string("a\u{0001}b"), "\"a\\u0001b\"");
+    }
+
+    #[test]
+    fn test_csv_field_quotes_only_when_needed() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field(r#"a"b"#), "\"a\"\"b\"");
+        assert_eq!(csv_field("a\nb"), "\"a\nb\"");
+        assert_eq!(csv_field("a,\"b\"\nc"), "\"a,\"\"b\"\"\nc\"");
+    }
+}
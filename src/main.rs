@@ -1,4 +1,6 @@
 mod cli;
+mod export;
+mod metrics;
 mod pg;
 mod tui;
 
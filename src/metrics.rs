@@ -0,0 +1,199 @@
+use crate::tui::app::{Tab, Update};
+use anyhow::{Context, Result};
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// A single `pg_stat_statements` row surfaced as a labeled metric series. Cardinality is
+/// bounded by only keeping the configured `--top-n` rows.
+pub struct StatementMetric {
+    pub query: String,
+    pub total_time_ms: f64,
+    pub mean_time_ms: f64,
+    pub calls: i64,
+}
+
+/// Stats collected by the refresh cycle and served to Prometheus scrapers.
+#[derive(Default)]
+pub struct Metrics {
+    pub cache_hit_pct: f64,
+    pub total_commits: i64,
+    pub total_rollbacks: i64,
+    pub total_backends: i64,
+    pub max_connections: i64,
+    pub conn_by_state: Vec<(String, i64)>,
+    pub top_statements: Vec<StatementMetric>,
+}
+
+/// Updates `registry` from a fetch for `tab`. The poll worker calls this for whichever tab
+/// the UI is on, and additionally for `Activity`/`Statements` whenever they weren't it, so a
+/// scrape always reflects live data regardless of what the operator has on screen.
+pub fn apply(registry: &Arc<Mutex<Metrics>>, top_n: u32, tab: Tab, update: &Update) {
+    let mut metrics = match registry.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+
+    match (tab, update) {
+        (Tab::Activity, Update::Activity(snapshot)) => {
+            metrics.cache_hit_pct = snapshot.cache_hit_pct;
+            metrics.total_commits = snapshot.total_commits;
+            metrics.total_rollbacks = snapshot.total_rollbacks;
+            metrics.total_backends = snapshot.total_backends;
+            metrics.max_connections = snapshot.max_connections;
+            metrics.conn_by_state.clone_from(&snapshot.conn_by_state);
+        }
+        (Tab::Statements, Update::Table(rows)) => {
+            metrics.top_statements = rows
+                .iter()
+                .take(top_n as usize)
+                .filter_map(|row| {
+                    Some(StatementMetric {
+                        query: row.first()?.clone(),
+                        total_time_ms: row.get(1)?.parse().ok()?,
+                        mean_time_ms: row.get(2)?.parse().ok()?,
+                        calls: row.get(3)?.parse().ok()?,
+                    })
+                })
+                .collect();
+        }
+        _ => {}
+    }
+}
+
+/// Starts the `/metrics` HTTP server on its own thread. Returns once the listener is bound
+/// so callers can surface a bind failure instead of discovering it silently later.
+pub fn spawn_server(addr: SocketAddr, registry: Arc<Mutex<Metrics>>) -> Result<()> {
+    let listener = TcpListener::bind(addr)
+        .with_context(|| format!("Failed to bind --metrics-addr {addr}"))?;
+
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            handle_connection(stream, &registry);
+        }
+    });
+
+    Ok(())
+}
+
+fn handle_connection(mut stream: TcpStream, registry: &Arc<Mutex<Metrics>>) {
+    let mut buf = [0u8; 1024];
+    let Ok(n) = stream.read(&mut buf) else {
+        return;
+    };
+    let request = String::from_utf8_lossy(&buf[..n]);
+    let path = request
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .unwrap_or("/");
+
+    let (status, body) = if path == "/metrics" {
+        let metrics = registry
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        ("200 OK", render(&metrics))
+    } else {
+        ("404 Not Found", String::new())
+    };
+
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    let _ = stream.write_all(response.as_bytes());
+}
+
+fn render(metrics: &Metrics) -> String {
+    let mut out = String::new();
+
+    out.push_str("# HELP pgmon_cache_hit_ratio Fraction of block reads served from the buffer cache.\n");
+    out.push_str("# TYPE pgmon_cache_hit_ratio gauge\n");
+    out.push_str(&format!(
+        "pgmon_cache_hit_ratio {}\n",
+        metrics.cache_hit_pct / 100.0
+    ));
+
+    out.push_str("# HELP pgmon_backends Current backend connections.\n");
+    out.push_str("# TYPE pgmon_backends gauge\n");
+    out.push_str(&format!("pgmon_backends {}\n", metrics.total_backends));
+
+    out.push_str("# HELP pgmon_max_connections Postgres max_connections setting.\n");
+    out.push_str("# TYPE pgmon_max_connections gauge\n");
+    out.push_str(&format!(
+        "pgmon_max_connections {}\n",
+        metrics.max_connections
+    ));
+
+    out.push_str("# HELP pgmon_commits_total Cumulative committed transactions.\n");
+    out.push_str("# TYPE pgmon_commits_total counter\n");
+    out.push_str(&format!("pgmon_commits_total {}\n", metrics.total_commits));
+
+    out.push_str("# HELP pgmon_rollbacks_total Cumulative rolled-back transactions.\n");
+    out.push_str("# TYPE pgmon_rollbacks_total counter\n");
+    out.push_str(&format!(
+        "pgmon_rollbacks_total {}\n",
+        metrics.total_rollbacks
+    ));
+
+    out.push_str("# HELP pgmon_connections Backend connections grouped by state.\n");
+    out.push_str("# TYPE pgmon_connections gauge\n");
+    for (state, count) in &metrics.conn_by_state {
+        out.push_str(&format!(
+            "pgmon_connections{{state=\"{}\"}} {count}\n",
+            escape_label(state)
+        ));
+    }
+
+    out.push_str(
+        "# HELP pgmon_statement_total_exec_milliseconds Cumulative execution time for top statements.\n",
+    );
+    out.push_str("# TYPE pgmon_statement_total_exec_milliseconds counter\n");
+    for stmt in &metrics.top_statements {
+        out.push_str(&format!(
+            "pgmon_statement_total_exec_milliseconds{{query=\"{}\"}} {}\n",
+            escape_label(&truncate_query(&stmt.query)),
+            stmt.total_time_ms
+        ));
+    }
+
+    out.push_str("# HELP pgmon_statement_mean_exec_milliseconds Mean execution time for top statements.\n");
+    out.push_str("# TYPE pgmon_statement_mean_exec_milliseconds gauge\n");
+    for stmt in &metrics.top_statements {
+        out.push_str(&format!(
+            "pgmon_statement_mean_exec_milliseconds{{query=\"{}\"}} {}\n",
+            escape_label(&truncate_query(&stmt.query)),
+            stmt.mean_time_ms
+        ));
+    }
+
+    out.push_str("# HELP pgmon_statement_calls_total Call count for top statements.\n");
+    out.push_str("# TYPE pgmon_statement_calls_total counter\n");
+    for stmt in &metrics.top_statements {
+        out.push_str(&format!(
+            "pgmon_statement_calls_total{{query=\"{}\"}} {}\n",
+            escape_label(&truncate_query(&stmt.query)),
+            stmt.calls
+        ));
+    }
+
+    out
+}
+
+/// Keeps label values reasonably sized regardless of how long the original query text was.
+fn truncate_query(query: &str) -> String {
+    const MAX_CHARS: usize = 80;
+    if query.chars().count() <= MAX_CHARS {
+        query.to_string()
+    } else {
+        format!("{}…", query.chars().take(MAX_CHARS).collect::<String>())
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
@@ -1,18 +1,170 @@
 use crate::pg::queries::{
     ACTIVE_QUERIES_QUERY, CONN_STATS_QUERY, DATABASE_QUERY, IO_QUERY, LOCKS_QUERY,
-    PERF_STATS_QUERY, STATEMENTS_QUERY,
+    PERF_STATS_QUERY, RELATIONS_QUERY, STATEMENTS_QUERY, WAIT_EVENTS_QUERY,
 };
-use anyhow::{Context, Result};
-use postgres::{Client, NoTls};
+use anyhow::{Context, Result, anyhow};
+use postgres::{Client, Config, NoTls};
+use rustls::{ClientConfig, RootCertStore};
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use std::fs;
+use std::sync::Arc;
+use tokio_postgres_rustls::MakeRustlsConnect;
+
+/// How strictly pgmon validates the server's TLS certificate, mirroring libpq's `sslmode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SslMode {
+    Disable,
+    Prefer,
+    Require,
+    VerifyFull,
+}
+
+impl SslMode {
+    pub fn parse(s: &str) -> Result<Self> {
+        match s {
+            "disable" => Ok(Self::Disable),
+            "prefer" => Ok(Self::Prefer),
+            "require" => Ok(Self::Require),
+            "verify-full" => Ok(Self::VerifyFull),
+            other => Err(anyhow!("unknown sslmode: {other}")),
+        }
+    }
+}
+
+/// Accepts any certificate without verification, used for `sslmode=prefer`/`require`
+/// where encryption is wanted but identity verification is not.
+#[derive(Debug)]
+struct NoCertVerification(Arc<rustls::crypto::CryptoProvider>);
+
+impl ServerCertVerifier for NoCertVerification {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &rustls::DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &rustls::DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<rustls::SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
+
+/// Builds the trust anchors for `verify-full`: the OS/system root store (so a corporate or
+/// private CA already trusted by the machine just works), plus anything in `--sslrootcert`.
+fn root_store(sslrootcert: Option<&str>) -> Result<RootCertStore> {
+    let mut store = RootCertStore::empty();
+    for cert in rustls_native_certs::load_native_certs().certs {
+        let _ = store.add(cert);
+    }
+
+    if let Some(path) = sslrootcert {
+        let pem = fs::read(path)
+            .with_context(|| format!("Failed to read --sslrootcert file: {path}"))?;
+        for cert in rustls_pemfile::certs(&mut pem.as_slice()) {
+            store
+                .add(cert.with_context(|| format!("Invalid certificate in {path}"))?)
+                .with_context(|| format!("Failed to add certificate from {path}"))?;
+        }
+    }
+
+    Ok(store)
+}
+
+/// Maps pgmon's `SslMode` onto the negotiation mode `tokio_postgres::Config::ssl_mode`
+/// understands. The connector built by `build_connector` only governs certificate
+/// *validation*; without also setting this, `require`/`verify-full` would still let the
+/// driver silently fall back to cleartext against a server that doesn't offer TLS.
+pub(crate) fn negotiation_mode(mode: SslMode) -> tokio_postgres::config::SslMode {
+    match mode {
+        SslMode::Disable => tokio_postgres::config::SslMode::Disable,
+        SslMode::Prefer => tokio_postgres::config::SslMode::Prefer,
+        SslMode::Require | SslMode::VerifyFull => tokio_postgres::config::SslMode::Require,
+    }
+}
+
+/// Installs the process-wide rustls `CryptoProvider` the first time it's needed.
+/// `ClientConfig::builder()` resolves this lazily and panics if nothing installed it, so
+/// this must run before the first connector is built regardless of which thread (the
+/// polling worker or the LISTEN/NOTIFY worker) gets there first.
+fn ensure_crypto_provider() {
+    static INIT: std::sync::Once = std::sync::Once::new();
+    INIT.call_once(|| {
+        let _ = rustls::crypto::ring::default_provider().install_default();
+    });
+}
+
+/// Builds the rustls connector shared by the synchronous polling client and the async
+/// LISTEN/NOTIFY worker; both accept any `MakeTlsConnect` implementation.
+pub(crate) fn build_connector(mode: SslMode, sslrootcert: Option<&str>) -> Result<MakeRustlsConnect> {
+    ensure_crypto_provider();
+    let config = match mode {
+        SslMode::VerifyFull => ClientConfig::builder()
+            .with_root_certificates(root_store(sslrootcert)?)
+            .with_no_client_auth(),
+        SslMode::Prefer | SslMode::Require => {
+            let provider = Arc::new(rustls::crypto::ring::default_provider());
+            ClientConfig::builder()
+                .dangerous()
+                .with_custom_certificate_verifier(Arc::new(NoCertVerification(provider)))
+                .with_no_client_auth()
+        }
+        SslMode::Disable => unreachable!("build_connector is never called for sslmode=disable"),
+    };
+    Ok(MakeRustlsConnect::new(config))
+}
 
 pub struct PgClient {
     client: Client,
 }
 
 impl PgClient {
-    pub fn new(dsn: &str) -> Result<Self> {
-        let client = Client::connect(dsn, NoTls)
-            .with_context(|| format!("Failed to connect to Postgres with DSN: {dsn}"))?;
+    pub fn new(dsn: &str, sslmode: SslMode, sslrootcert: Option<&str>) -> Result<Self> {
+        let client = if sslmode == SslMode::Disable {
+            Client::connect(dsn, NoTls)
+                .with_context(|| format!("Failed to connect to Postgres with DSN: {dsn}"))?
+        } else {
+            let mut config: Config = dsn
+                .parse()
+                .with_context(|| format!("Failed to parse DSN: {dsn}"))?;
+            config.ssl_mode(negotiation_mode(sslmode));
+            let connector = build_connector(sslmode, sslrootcert)?;
+            config
+                .connect(connector)
+                .with_context(|| format!("Failed to connect to Postgres with DSN: {dsn}"))?
+        };
         Ok(Self { client })
     }
 
@@ -37,6 +189,32 @@ impl PgClient {
             .collect())
     }
 
+    /// Returns the connected database's name, used to anchor the Database tab's tree to the
+    /// one database whose schemas/relations can actually be introspected over this connection.
+    pub fn fetch_current_database(&mut self) -> Result<String> {
+        let row = self.client.query_one("SELECT current_database()", &[])?;
+        Ok(row.get::<_, String>(0))
+    }
+
+    /// Schema/relation breakdown of the connected database, feeding the Database tab's tree
+    /// sidebar.
+    pub fn fetch_relations(&mut self) -> Result<Vec<Vec<String>>> {
+        let rows = self.client.query(RELATIONS_QUERY, &[])?;
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                vec![
+                    row.get::<_, String>(0),
+                    row.get::<_, String>(1),
+                    row.get::<_, i64>(2).to_string(),
+                    row.get::<_, Option<i64>>(3).unwrap_or(0).to_string(),
+                    row.get::<_, i64>(4).to_string(),
+                    row.get::<_, i64>(5).to_string(),
+                ]
+            })
+            .collect())
+    }
+
     pub fn fetch_locks(&mut self) -> Result<Vec<Vec<String>>> {
         let rows = self.client.query(LOCKS_QUERY, &[])?;
         Ok(rows
@@ -100,11 +278,37 @@ impl PgClient {
                     row.get::<_, i64>(3).to_string(),
                     row.get::<_, f64>(4).to_string(),
                     row.get::<_, f64>(5).to_string(),
+                    row.get::<_, Option<String>>(6).unwrap_or_default(),
                 ]
             })
             .collect())
     }
 
+    /// Runs a read-only `EXPLAIN (FORMAT TEXT)` of `query` and returns the plan as one
+    /// string. `query` comes from `pg_stat_statements`, not user input, so it is inlined
+    /// directly since `EXPLAIN` cannot take its target statement as a bind parameter.
+    ///
+    /// `pg_stat_statements.query` is the *normalized* text, with literals replaced by
+    /// `$1`, `$2`, … — running that through `EXPLAIN` with nothing bound to those
+    /// placeholders raises `there is no parameter $1`, so we detect that case up front
+    /// and return an explanatory message instead of a query error.
+    pub fn fetch_explain(&mut self, query: &str) -> Result<String> {
+        if has_unbound_placeholders(query) {
+            return Ok(format!(
+                "cannot EXPLAIN: this is a normalized pg_stat_statements entry with bind \
+                 placeholders ($1, $2, …) and no values to substitute for them.\n\n{query}"
+            ));
+        }
+        let rows = self
+            .client
+            .query(&format!("EXPLAIN (FORMAT TEXT) {query}"), &[])?;
+        Ok(rows
+            .into_iter()
+            .map(|row| row.get::<_, String>(0))
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+
     pub fn fetch_conn_stats(&mut self) -> Result<Vec<(String, i64)>> {
         let rows = self.client.query(CONN_STATS_QUERY, &[])?;
         Ok(rows
@@ -113,6 +317,17 @@ impl PgClient {
             .collect())
     }
 
+    /// Top wait events currently seen across backends, labeled `"{wait_event_type}:{wait_event}"`
+    /// (or `"none"` for backends not waiting), so operators can tell at a glance whether load is
+    /// CPU, lock, or IO bound.
+    pub fn fetch_wait_events(&mut self) -> Result<Vec<(String, i64)>> {
+        let rows = self.client.query(WAIT_EVENTS_QUERY, &[])?;
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.get::<_, String>(0), row.get::<_, i64>(1)))
+            .collect())
+    }
+
     pub fn fetch_active_queries(&mut self) -> Result<Vec<Vec<String>>> {
         let rows = self.client.query(ACTIVE_QUERIES_QUERY, &[])?;
         Ok(rows
@@ -158,3 +373,13 @@ impl PgClient {
         Ok(row.is_some())
     }
 }
+
+/// Whether `query` contains a `$N` placeholder (`pg_stat_statements`'s normalized-literal
+/// marker). Any `$` followed by a digit counts, wherever it appears in the text.
+fn has_unbound_placeholders(query: &str) -> bool {
+    let bytes = query.as_bytes();
+    bytes
+        .iter()
+        .enumerate()
+        .any(|(i, &b)| b == b'$' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit))
+}
@@ -34,13 +34,14 @@ LIMIT 500
 ";
 
 pub const STATEMENTS_QUERY: &str = r"
-SELECT 
-    query, 
-    total_exec_time as total_time, 
-    mean_exec_time as mean_time, 
-    calls, 
-    shared_blk_read_time as blk_read_time, 
-    shared_blk_write_time as blk_write_time
+SELECT
+    query,
+    total_exec_time as total_time,
+    mean_exec_time as mean_time,
+    calls,
+    shared_blk_read_time as blk_read_time,
+    shared_blk_write_time as blk_write_time,
+    queryid::text as queryid
 FROM pg_stat_statements
 ORDER BY total_exec_time DESC
 LIMIT 500
@@ -65,6 +66,29 @@ ORDER BY
     END
 ";
 
+pub const WAIT_EVENTS_QUERY: &str = r"
+SELECT
+    COALESCE(wait_event_type || ':' || wait_event, 'none') as wait_event,
+    COUNT(*)::bigint as count
+FROM pg_stat_activity
+WHERE pid <> pg_backend_pid()
+GROUP BY wait_event_type, wait_event
+ORDER BY count DESC
+LIMIT 10
+";
+
+pub const RELATIONS_QUERY: &str = r"
+SELECT
+    schemaname,
+    relname,
+    seq_scan,
+    idx_scan,
+    n_live_tup,
+    n_dead_tup
+FROM pg_stat_user_tables
+ORDER BY schemaname, relname
+";
+
 pub const ACTIVE_QUERIES_QUERY: &str = r"
 SELECT
     pid::text,
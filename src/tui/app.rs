@@ -1,20 +1,37 @@
-use crate::pg::client::PgClient;
-use anyhow::Result;
+use crate::pg::client::{PgClient, SslMode};
+use anyhow::{Context, Result};
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, MouseButton, MouseEvent,
+        MouseEventKind,
+    },
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
-use ratatui::{Terminal, backend::CrosstermBackend};
+use ratatui::{Terminal, backend::CrosstermBackend, layout::Rect, widgets::TableState};
 use std::{
-    collections::VecDeque,
+    collections::{HashMap, HashSet, VecDeque},
     io,
-    sync::mpsc,
+    sync::{
+        Arc, Mutex,
+        mpsc::{self, RecvTimeoutError},
+    },
     thread,
-    time::{Duration, Instant},
+    time::Duration,
 };
 
+use crate::metrics::Metrics;
+use crate::tui::theme::Theme;
+use crate::tui::tree::{self, NodeKind, TreeNode};
+
 const CONN_HISTORY_SIZE: usize = 600;
+const EVENTS_HISTORY_SIZE: usize = 500;
+const STATEMENT_HISTORY_SIZE: usize = 120;
+/// Rows moved per `PageUp`/`PageDown` press.
+const PAGE_SIZE: usize = 10;
+
+/// Failures are capped so the worker's poll interval backs off at most this many doublings.
+const MAX_BACKOFF_STEPS: u32 = 5;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Tab {
@@ -23,6 +40,17 @@ pub enum Tab {
     Locks,
     IO,
     Statements,
+    Events,
+}
+
+/// A single `NOTIFY` delivered on a subscribed channel, tagged with arrival time and
+/// source PID so operators can correlate it with other activity.
+#[derive(Clone)]
+pub struct EventRecord {
+    pub received_at: String,
+    pub pid: i32,
+    pub channel: String,
+    pub payload: String,
 }
 
 #[derive(Clone)]
@@ -36,6 +64,12 @@ pub struct DashboardStats {
     pub max_connections: i64,
     /// Ring buffer: `(active, idle, total)` per refresh tick
     pub conn_history: VecDeque<(i64, i64, i64)>,
+    /// Commits added since the previous refresh, one entry per tick, for the sparkline.
+    pub commits_history: VecDeque<i64>,
+    /// Rollbacks added since the previous refresh, one entry per tick, for the sparkline.
+    pub rollbacks_history: VecDeque<i64>,
+    /// `(wait_event_type:wait_event, count)`, highest count first.
+    pub wait_events: Vec<(String, i64)>,
 }
 
 impl Default for DashboardStats {
@@ -49,39 +83,170 @@ impl Default for DashboardStats {
             total_backends: 0,
             max_connections: 0,
             conn_history: VecDeque::new(),
+            commits_history: VecDeque::new(),
+            rollbacks_history: VecDeque::new(),
+            wait_events: Vec::new(),
         }
     }
 }
 
+/// Derived per-refresh deltas and recent-activity history for one `pg_stat_statements`
+/// row, keyed by `queryid` so it survives row order and query text changing between
+/// refreshes (e.g. once literals are normalized differently).
+#[derive(Clone, Default)]
+pub struct StatementStats {
+    pub query: String,
+    pub total_time_ms: f64,
+    pub mean_time_ms: f64,
+    pub calls: i64,
+    pub calls_per_sec: f64,
+    pub total_time_delta_ms: f64,
+    pub mean_time_delta_ms: f64,
+    /// Ring buffer of calls added per refresh tick, newest last.
+    pub history: VecDeque<i64>,
+}
+
+/// A fully-fetched snapshot for the Database tab: the cluster-wide per-database totals plus
+/// the connected database's schema/relation breakdown, from which `tree::build_tree` derives
+/// the sidebar.
+pub struct DatabaseSnapshot {
+    pub databases: Vec<Vec<String>>,
+    pub current_db: String,
+    pub relations: Vec<Vec<String>>,
+}
+
+/// A fully-fetched snapshot for the Activity tab, built on the worker thread.
+pub struct ActivitySnapshot {
+    pub conn_by_state: Vec<(String, i64)>,
+    pub active_queries: Vec<Vec<String>>,
+    pub cache_hit_pct: f64,
+    pub total_commits: i64,
+    pub total_rollbacks: i64,
+    pub total_backends: i64,
+    pub max_connections: i64,
+    /// `(wait_event_type:wait_event, count)`, highest count first.
+    pub wait_events: Vec<(String, i64)>,
+}
+
+/// Sent from the worker thread to the UI thread over the update channel.
+pub enum Update {
+    Activity(ActivitySnapshot),
+    Database(DatabaseSnapshot),
+    Table(Vec<Vec<String>>),
+    Notification(EventRecord),
+    /// Result of an `EXPLAIN` requested via `Command::Explain`, already formatted for display.
+    Explain(String),
+    Error(String),
+}
+
+/// Sent from the UI thread to the worker thread to change what/when it fetches.
+enum Command {
+    SetTab(Tab),
+    /// Run `EXPLAIN (FORMAT TEXT)` for this statement and report it back as `Update::Explain`.
+    Explain(String),
+}
+
 pub struct App {
     pub dsn: String,
     pub refresh_ms: u64,
     pub top_n: u32,
+    pub theme: Theme,
+    pub sslmode: SslMode,
+    pub sslrootcert: Option<String>,
+    pub listen_channels: Vec<String>,
+    pub metrics_addr: Option<std::net::SocketAddr>,
     pub current_tab: Tab,
     pub data: Vec<Vec<String>>,
     pub dashboard: DashboardStats,
+    /// Ring buffer of recent LISTEN/NOTIFY deliveries, newest last.
+    pub events: VecDeque<EventRecord>,
     pub should_quit: bool,
-    pub last_refresh: Instant,
     pub selected_row: usize,
+    /// Set while the worker's last fetch failed and it is waiting to reconnect.
+    pub reconnecting: bool,
+    /// Deltas and history per `queryid`, refreshed every time the Statements tab is polled.
+    pub statement_stats: HashMap<String, StatementStats>,
+    /// Database tab's Database → Schema → Relation sidebar, flattened and filtered to
+    /// visible nodes by `tree::build_tree`.
+    pub tree: Vec<TreeNode>,
+    /// Keys (see `TreeNode::key`) of tree nodes the user has collapsed.
+    tree_collapsed: HashSet<String>,
+    databases: Vec<Vec<String>>,
+    current_db: String,
+    relations: Vec<Vec<String>>,
+    /// Stats for the relation last drilled into with `Enter` on the Database tab.
+    pub detail_relation: Option<Vec<String>>,
+    /// Scroll offset/selection for whichever `Table` is on screen, kept in sync with
+    /// `selected_row` and rendered via `f.render_stateful_widget`.
+    pub table_state: TableState,
+    /// Whether the Statements drill-down pane is open for `selected_row`.
+    pub show_detail: bool,
+    pub detail_query: Option<String>,
+    pub detail_explain: Option<String>,
+    /// Whether the `?` keybinding help overlay is showing.
+    pub show_help: bool,
+    /// Clickable `(tab, title rect)` pairs recorded by `draw_tabs` on the last frame, so
+    /// mouse clicks can be mapped back to a `Tab` without re-deriving the `Tabs` widget's
+    /// layout in the event loop.
+    pub tab_rects: Vec<(Tab, Rect)>,
+    /// The bordered table rect recorded by `draw_table`/`draw_active_queries_panel` on the
+    /// last frame, so mouse clicks/scrolls can be translated into a row of `selected_row`.
+    pub table_area: Rect,
+    updates: Option<mpsc::Receiver<Update>>,
+    commands: Option<mpsc::Sender<Command>>,
 }
 
 impl App {
-    pub fn new(dsn: String, refresh_ms: u64, top_n: u32, home_view: &str, _sort: &str) -> Self {
+    pub fn new(
+        dsn: String,
+        refresh_ms: u64,
+        top_n: u32,
+        home_view: &str,
+        _sort: &str,
+        theme: Theme,
+        sslmode: &str,
+        sslrootcert: Option<String>,
+        listen_channels: Vec<String>,
+        metrics_addr: Option<std::net::SocketAddr>,
+    ) -> Self {
         let current_tab = match home_view {
             "statements" => Tab::Statements,
             _ => Tab::Activity,
         };
+        let sslmode = SslMode::parse(sslmode).unwrap_or(SslMode::Prefer);
 
         Self {
             dsn,
             refresh_ms,
             top_n,
+            theme,
+            sslmode,
+            sslrootcert,
+            listen_channels,
+            metrics_addr,
             current_tab,
             data: Vec::new(),
             dashboard: DashboardStats::default(),
+            events: VecDeque::new(),
             should_quit: false,
-            last_refresh: Instant::now(),
             selected_row: 0,
+            reconnecting: false,
+            statement_stats: HashMap::new(),
+            tree: Vec::new(),
+            tree_collapsed: HashSet::new(),
+            databases: Vec::new(),
+            current_db: String::new(),
+            relations: Vec::new(),
+            detail_relation: None,
+            table_state: TableState::default(),
+            show_detail: false,
+            detail_query: None,
+            detail_explain: None,
+            show_help: false,
+            tab_rects: Vec::new(),
+            table_area: Rect::default(),
+            updates: None,
+            commands: None,
         }
     }
 
@@ -93,63 +258,64 @@ impl App {
         let mut terminal = Terminal::new(backend)?;
         terminal.clear()?;
 
-        let (tx, _rx) = mpsc::channel();
+        let (update_tx, update_rx) = mpsc::channel();
+        let (command_tx, command_rx) = mpsc::channel();
+
         let dsn = self.dsn.clone();
+        let sslmode = self.sslmode;
+        let sslrootcert = self.sslrootcert.clone();
+        let refresh_ms = self.refresh_ms;
+        let initial_tab = self.current_tab;
 
-        // Polling thread
-        thread::spawn(move || {
-            let _client = match PgClient::new(&dsn) {
-                Ok(c) => c,
-                Err(e) => {
-                    let _ = tx.send(Err(e));
-                    return;
-                }
-            };
+        if !self.listen_channels.is_empty() {
+            let dsn = self.dsn.clone();
+            let sslmode = self.sslmode;
+            let sslrootcert = self.sslrootcert.clone();
+            let channels = self.listen_channels.clone();
+            let update_tx = update_tx.clone();
+            thread::spawn(move || {
+                listen_worker(&dsn, sslmode, sslrootcert.as_deref(), &channels, &update_tx);
+            });
+        }
 
-            loop {
-                thread::sleep(Duration::from_millis(100));
-                let _ = tx.send(Ok(Tab::Activity));
+        let metrics_registry = match self.metrics_addr {
+            Some(addr) => {
+                let registry = Arc::new(Mutex::new(Metrics::default()));
+                crate::metrics::spawn_server(addr, registry.clone())?;
+                Some(registry)
             }
+            None => None,
+        };
+        let top_n = self.top_n;
+
+        thread::spawn(move || {
+            poll_worker(
+                &dsn,
+                sslmode,
+                sslrootcert.as_deref(),
+                refresh_ms,
+                initial_tab,
+                &update_tx,
+                &command_rx,
+                metrics_registry,
+                top_n,
+            );
         });
 
-        self.refresh_data()?;
+        self.updates = Some(update_rx);
+        self.commands = Some(command_tx);
 
         while !self.should_quit {
+            self.drain_updates();
             terminal.draw(|f| crate::tui::ui::draw(f, self))?;
 
-            if let Ok(true) = event::poll(Duration::from_millis(50))
-                && let Event::Key(key) = event::read()?
-            {
-                match key.code {
-                    KeyCode::Char('q') => self.should_quit = true,
-                    KeyCode::Char('1') => self.set_tab(Tab::Activity),
-                    KeyCode::Char('2') => self.set_tab(Tab::Database),
-                    KeyCode::Char('3') => self.set_tab(Tab::Locks),
-                    KeyCode::Char('4') => self.set_tab(Tab::IO),
-                    KeyCode::Char('5') => self.set_tab(Tab::Statements),
-                    KeyCode::Down => {
-                        let len = if self.current_tab == Tab::Activity {
-                            self.dashboard.active_queries.len()
-                        } else {
-                            self.data.len()
-                        };
-                        if len > 0 && self.selected_row < len - 1 {
-                            self.selected_row += 1;
-                        }
-                    }
-                    KeyCode::Up => {
-                        if self.selected_row > 0 {
-                            self.selected_row -= 1;
-                        }
-                    }
+            if let Ok(true) = event::poll(Duration::from_millis(50)) {
+                match event::read()? {
+                    Event::Mouse(mouse) => self.handle_mouse(mouse),
+                    Event::Key(key) => self.handle_key(key.code),
                     _ => {}
                 }
             }
-
-            if self.last_refresh.elapsed() >= Duration::from_millis(self.refresh_ms) {
-                self.refresh_data()?;
-                self.last_refresh = Instant::now();
-            }
         }
 
         disable_raw_mode()?;
@@ -163,53 +329,606 @@ impl App {
         Ok(())
     }
 
+    fn handle_key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Char('q') => self.should_quit = true,
+            KeyCode::Char('?') => self.show_help = !self.show_help,
+            _ if self.show_help => {}
+            KeyCode::Char('1') => self.set_tab(Tab::Activity),
+            KeyCode::Char('2') => self.set_tab(Tab::Database),
+            KeyCode::Char('3') => self.set_tab(Tab::Locks),
+            KeyCode::Char('4') => self.set_tab(Tab::IO),
+            KeyCode::Char('5') => self.set_tab(Tab::Statements),
+            KeyCode::Char('6') => self.set_tab(Tab::Events),
+            KeyCode::Esc if self.show_detail => self.show_detail = false,
+            KeyCode::Enter if self.current_tab == Tab::Statements => {
+                self.toggle_detail();
+            }
+            KeyCode::Enter if self.current_tab == Tab::Database => self.drill_relation(),
+            KeyCode::Left if self.current_tab == Tab::Database => self.collapse_selected(),
+            KeyCode::Right if self.current_tab == Tab::Database => self.expand_selected(),
+            KeyCode::Down => self.select_next_row(),
+            KeyCode::Up => self.select_prev_row(),
+            KeyCode::PageDown => {
+                let len = self.current_row_count();
+                self.selected_row = (self.selected_row + PAGE_SIZE).min(len.saturating_sub(1));
+            }
+            KeyCode::PageUp => {
+                self.selected_row = self.selected_row.saturating_sub(PAGE_SIZE);
+            }
+            KeyCode::Home => self.selected_row = 0,
+            KeyCode::End => {
+                self.selected_row = self.current_row_count().saturating_sub(1);
+            }
+            _ => {}
+        }
+    }
+
+    /// Dispatches a mouse event: clicking a tab title switches to it, clicking a row in
+    /// the currently visible table selects it, and the wheel moves the selection by one
+    /// row — mirroring `KeyCode::Up`/`Down` so the viewport scrolls via the same
+    /// `table_state`-driven path `draw_table`/`draw_active_queries_panel` already use.
+    fn handle_mouse(&mut self, mouse: MouseEvent) {
+        if self.show_help {
+            return;
+        }
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                if let Some(tab) = self.tab_at(mouse.column, mouse.row) {
+                    self.set_tab(tab);
+                } else if self.row_clicks_supported() && rect_contains(self.table_area, mouse.column, mouse.row) {
+                    self.select_row_at(mouse.row);
+                }
+            }
+            MouseEventKind::ScrollDown
+                if self.row_clicks_supported() && rect_contains(self.table_area, mouse.column, mouse.row) =>
+            {
+                self.select_next_row();
+            }
+            MouseEventKind::ScrollUp
+                if self.row_clicks_supported() && rect_contains(self.table_area, mouse.column, mouse.row) =>
+            {
+                self.select_prev_row();
+            }
+            _ => {}
+        }
+    }
+
+    fn tab_at(&self, column: u16, row: u16) -> Option<Tab> {
+        self.tab_rects
+            .iter()
+            .find(|(_, rect)| rect_contains(*rect, column, row))
+            .map(|(tab, _)| *tab)
+    }
+
+    /// Whether `table_area` (recorded by `draw_table`/`draw_active_queries_panel`) reflects
+    /// the view currently on screen, as opposed to a stale rect left over from a tab that
+    /// renders something else (the Database tree, the Events log, or the Statements detail
+    /// pane don't record it).
+    fn row_clicks_supported(&self) -> bool {
+        match self.current_tab {
+            Tab::Activity => true,
+            Tab::Statements => !self.show_detail,
+            Tab::Locks | Tab::IO => true,
+            Tab::Database | Tab::Events => false,
+        }
+    }
+
+    /// Maps a click's screen row onto a row of `table_area`, accounting for the block
+    /// border, header row, and header's `bottom_margin`, plus the viewport's current
+    /// scroll offset from `table_state`.
+    fn select_row_at(&mut self, row: u16) {
+        const HEADER_ROWS: u16 = 2;
+        let first_row_y = self.table_area.y + 1 + HEADER_ROWS;
+        let last_row_y = self.table_area.y + self.table_area.height.saturating_sub(1);
+        if row < first_row_y || row >= last_row_y {
+            return;
+        }
+        let clicked = usize::from(row - first_row_y) + self.table_state.offset();
+        let len = self.current_row_count();
+        if len > 0 {
+            self.selected_row = clicked.min(len - 1);
+        }
+    }
+
+    fn select_next_row(&mut self) {
+        let len = self.current_row_count();
+        if len > 0 && self.selected_row < len - 1 {
+            self.selected_row += 1;
+        }
+    }
+
+    fn select_prev_row(&mut self) {
+        if self.selected_row > 0 {
+            self.selected_row -= 1;
+        }
+    }
+
     fn set_tab(&mut self, tab: Tab) {
         self.current_tab = tab;
         self.selected_row = 0;
-        let _ = self.refresh_data();
+        self.show_detail = false;
+        self.detail_relation = None;
+        if let Some(commands) = &self.commands {
+            let _ = commands.send(Command::SetTab(tab));
+        }
     }
 
-    fn refresh_data(&mut self) -> Result<()> {
-        let mut client = PgClient::new(&self.dsn)?;
+    /// Opens the drill-down pane for `selected_row` (requesting its `EXPLAIN` from the
+    /// worker) if it is closed, or closes it if it is already open.
+    fn toggle_detail(&mut self) {
+        if self.show_detail {
+            self.show_detail = false;
+            return;
+        }
+        let Some(query) = self.data.get(self.selected_row).and_then(|row| row.first()) else {
+            return;
+        };
+        self.detail_query = Some(query.clone());
+        self.detail_explain = None;
+        if let Some(commands) = &self.commands {
+            let _ = commands.send(Command::Explain(query.clone()));
+        }
+        self.show_detail = true;
+    }
+
+    /// Row count for whichever list/table backs the current tab, used to clamp
+    /// `selected_row` for the navigation keys and mouse row selection.
+    fn current_row_count(&self) -> usize {
         match self.current_tab {
-            Tab::Activity => {
-                self.dashboard.conn_by_state = client.fetch_conn_stats()?;
-                self.dashboard.active_queries = client.fetch_active_queries()?;
-                let (cache_hit, commits, rollbacks, backends, max_conn) =
-                    client.fetch_perf_stats()?;
-                self.dashboard.cache_hit_pct = cache_hit;
-                self.dashboard.total_commits = commits;
-                self.dashboard.total_rollbacks = rollbacks;
-                self.dashboard.total_backends = backends;
-                self.dashboard.max_connections = max_conn;
-
-                let active_count = self
-                    .dashboard
-                    .conn_by_state
-                    .iter()
-                    .find(|(s, _)| s == "active")
-                    .map_or(0, |(_, c)| *c);
-                let idle_count = self
-                    .dashboard
-                    .conn_by_state
-                    .iter()
-                    .find(|(s, _)| s == "idle")
-                    .map_or(0, |(_, c)| *c);
-                let total_count: i64 = self.dashboard.conn_by_state.iter().map(|(_, c)| c).sum();
-                if self.dashboard.conn_history.len() >= CONN_HISTORY_SIZE {
-                    self.dashboard.conn_history.pop_front();
+            Tab::Activity => self.dashboard.active_queries.len(),
+            Tab::Events => self.events.len(),
+            Tab::Database => self.tree.iter().filter(|n| n.visible).count(),
+            _ => self.data.len(),
+        }
+    }
+
+    fn selected_tree_node(&self) -> Option<&TreeNode> {
+        self.tree.iter().filter(|n| n.visible).nth(self.selected_row)
+    }
+
+    /// Collapses the selected Database/Schema node, hiding its descendants.
+    fn collapse_selected(&mut self) {
+        let Some(node) = self.selected_tree_node() else {
+            return;
+        };
+        if node.kind == NodeKind::Relation {
+            return;
+        }
+        self.tree_collapsed.insert(node.key.clone());
+        self.rebuild_tree();
+    }
+
+    /// Expands the selected Database/Schema node, revealing its descendants again.
+    fn expand_selected(&mut self) {
+        let Some(node) = self.selected_tree_node() else {
+            return;
+        };
+        self.tree_collapsed.remove(&node.key);
+        self.rebuild_tree();
+    }
+
+    /// Fills the right-hand detail panel with the selected Relation node's per-table stats.
+    fn drill_relation(&mut self) {
+        let Some(node) = self.selected_tree_node() else {
+            return;
+        };
+        if node.kind == NodeKind::Relation {
+            self.detail_relation = Some(node.stats.clone());
+        }
+    }
+
+    fn rebuild_tree(&mut self) {
+        self.tree = tree::build_tree(
+            &self.databases,
+            &self.current_db,
+            &self.relations,
+            &self.tree_collapsed,
+        );
+    }
+
+    /// Drains any updates the worker has produced since the last redraw, without
+    /// blocking the UI thread.
+    fn drain_updates(&mut self) {
+        let Some(updates) = &self.updates else {
+            return;
+        };
+        while let Ok(update) = updates.try_recv() {
+            match update {
+                Update::Activity(snapshot) => {
+                    self.reconnecting = false;
+                    self.apply_activity(snapshot);
                 }
-                self.dashboard
-                    .conn_history
-                    .push_back((active_count, idle_count, total_count));
+                Update::Database(snapshot) => {
+                    self.reconnecting = false;
+                    self.databases = snapshot.databases;
+                    self.current_db = snapshot.current_db;
+                    self.relations = snapshot.relations;
+                    self.rebuild_tree();
+                }
+                Update::Table(rows) => {
+                    self.reconnecting = false;
+                    if self.current_tab == Tab::Statements {
+                        self.apply_statements(rows);
+                    } else {
+                        self.data = rows;
+                    }
+                }
+                Update::Notification(event) => {
+                    if self.events.len() >= EVENTS_HISTORY_SIZE {
+                        self.events.pop_front();
+                    }
+                    self.events.push_back(event);
+                }
+                Update::Explain(plan) => self.detail_explain = Some(plan),
+                Update::Error(_) => self.reconnecting = true,
+            }
+        }
+    }
+
+    fn apply_activity(&mut self, snapshot: ActivitySnapshot) {
+        let active_count = snapshot
+            .conn_by_state
+            .iter()
+            .find(|(s, _)| s == "active")
+            .map_or(0, |(_, c)| *c);
+        let idle_count = snapshot
+            .conn_by_state
+            .iter()
+            .find(|(s, _)| s == "idle")
+            .map_or(0, |(_, c)| *c);
+        let total_count: i64 = snapshot.conn_by_state.iter().map(|(_, c)| c).sum();
 
-                self.data = Vec::new();
+        // First tick has no prior totals to diff against, so the sparklines start on the
+        // second refresh rather than showing one spurious spike of the full cumulative value.
+        let has_prior_tick = !self.dashboard.conn_history.is_empty();
+        let commits_delta = (snapshot.total_commits - self.dashboard.total_commits).max(0);
+        let rollbacks_delta = (snapshot.total_rollbacks - self.dashboard.total_rollbacks).max(0);
+
+        self.dashboard.conn_by_state = snapshot.conn_by_state;
+        self.dashboard.active_queries = snapshot.active_queries;
+        self.dashboard.cache_hit_pct = snapshot.cache_hit_pct;
+        self.dashboard.total_commits = snapshot.total_commits;
+        self.dashboard.total_rollbacks = snapshot.total_rollbacks;
+        self.dashboard.total_backends = snapshot.total_backends;
+        self.dashboard.max_connections = snapshot.max_connections;
+        self.dashboard.wait_events = snapshot.wait_events;
+
+        if self.dashboard.conn_history.len() >= CONN_HISTORY_SIZE {
+            self.dashboard.conn_history.pop_front();
+        }
+        self.dashboard
+            .conn_history
+            .push_back((active_count, idle_count, total_count));
+
+        if has_prior_tick {
+            if self.dashboard.commits_history.len() >= CONN_HISTORY_SIZE {
+                self.dashboard.commits_history.pop_front();
+            }
+            self.dashboard.commits_history.push_back(commits_delta);
+
+            if self.dashboard.rollbacks_history.len() >= CONN_HISTORY_SIZE {
+                self.dashboard.rollbacks_history.pop_front();
             }
-            Tab::Database => self.data = client.fetch_database_stats()?,
-            Tab::Locks => self.data = client.fetch_locks()?,
-            Tab::IO => self.data = client.fetch_io_stats()?,
-            Tab::Statements => self.data = client.fetch_statements()?,
+            self.dashboard.rollbacks_history.push_back(rollbacks_delta);
+        }
+
+        self.data = Vec::new();
+    }
+
+    /// Diffs the freshly-fetched statements snapshot against `statement_stats` (keyed by
+    /// `queryid`, the last column of each row) to derive per-refresh deltas, then stores
+    /// the raw rows as usual for the table/detail pane to read.
+    #[allow(clippy::cast_precision_loss)]
+    fn apply_statements(&mut self, rows: Vec<Vec<String>>) {
+        let elapsed_secs = self.refresh_ms as f64 / 1000.0;
+
+        for row in &rows {
+            let Some(queryid) = row.get(6).filter(|s| !s.is_empty()) else {
+                continue;
+            };
+            let query = row.first().cloned().unwrap_or_default();
+            let total_time_ms: f64 = row.get(1).and_then(|s| s.parse().ok()).unwrap_or(0.0);
+            let mean_time_ms: f64 = row.get(2).and_then(|s| s.parse().ok()).unwrap_or(0.0);
+            let calls: i64 = row.get(3).and_then(|s| s.parse().ok()).unwrap_or(0);
+
+            let is_new = !self.statement_stats.contains_key(queryid.as_str());
+            let stats = self
+                .statement_stats
+                .entry(queryid.clone())
+                .or_insert_with(StatementStats::default);
+
+            // No previous snapshot for this queryid to diff against: record the baseline
+            // only, so the first deltas aren't the full cumulative totals.
+            if is_new {
+                stats.query = query;
+                stats.total_time_ms = total_time_ms;
+                stats.mean_time_ms = mean_time_ms;
+                stats.calls = calls;
+                continue;
+            }
+
+            let calls_delta = (calls - stats.calls).max(0);
+            stats.total_time_delta_ms = total_time_ms - stats.total_time_ms;
+            stats.mean_time_delta_ms = mean_time_ms - stats.mean_time_ms;
+            stats.calls_per_sec = if elapsed_secs > 0.0 {
+                calls_delta as f64 / elapsed_secs
+            } else {
+                0.0
+            };
+            stats.query = query;
+            stats.total_time_ms = total_time_ms;
+            stats.mean_time_ms = mean_time_ms;
+            stats.calls = calls;
+
+            if stats.history.len() >= STATEMENT_HISTORY_SIZE {
+                stats.history.pop_front();
+            }
+            stats.history.push_back(calls_delta);
+        }
+
+        // pg_stat_statements entries come and go as queries age out; without this, a
+        // queryid that stops showing up in the (top-500) snapshot would linger here forever.
+        let live_queryids: std::collections::HashSet<&str> = rows
+            .iter()
+            .filter_map(|row| row.get(6).map(String::as_str).filter(|s| !s.is_empty()))
+            .collect();
+        self.statement_stats
+            .retain(|queryid, _| live_queryids.contains(queryid.as_str()));
+
+        self.data = rows;
+    }
+}
+
+/// Whether `(column, row)` falls inside `rect`.
+fn rect_contains(rect: Rect, column: u16, row: u16) -> bool {
+    column >= rect.x
+        && column < rect.x + rect.width
+        && row >= rect.y
+        && row < rect.y + rect.height
+}
+
+/// Runs on its own thread, owning the `PgClient` and fetching data for whichever tab is
+/// current on every `refresh_ms` tick (backed off on repeated failures), so slow queries
+/// never block rendering or keypress handling on the UI thread.
+fn poll_worker(
+    dsn: &str,
+    sslmode: SslMode,
+    sslrootcert: Option<&str>,
+    refresh_ms: u64,
+    mut current_tab: Tab,
+    updates: &mpsc::Sender<Update>,
+    commands: &mpsc::Receiver<Command>,
+    metrics_registry: Option<Arc<Mutex<Metrics>>>,
+    top_n: u32,
+) {
+    let mut client: Option<PgClient> = None;
+    let mut consecutive_failures: u32 = 0;
+    // Fetch immediately on the first iteration instead of waiting out a full refresh
+    // interval first, so the UI isn't left blank on startup.
+    let mut skip_wait = true;
+
+    loop {
+        if skip_wait {
+            skip_wait = false;
+        } else {
+            let backoff = 1u64 << consecutive_failures.min(MAX_BACKOFF_STEPS);
+            match commands.recv_timeout(Duration::from_millis(refresh_ms.saturating_mul(backoff)))
+            {
+                Ok(Command::SetTab(tab)) => current_tab = tab,
+                Ok(Command::Explain(query)) => {
+                    let plan = match run_explain(&mut client, dsn, sslmode, sslrootcert, &query) {
+                        Ok(plan) => plan,
+                        Err(e) => format!("EXPLAIN failed: {e}"),
+                    };
+                    if updates.send(Update::Explain(plan)).is_err() {
+                        return;
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+        }
+
+        match fetch_update(&mut client, dsn, sslmode, sslrootcert, current_tab) {
+            Ok(update) => {
+                consecutive_failures = 0;
+                if let Some(registry) = &metrics_registry {
+                    crate::metrics::apply(registry, top_n, current_tab, &update);
+                    // The exporter is a scrape target independent of the TUI, so it must
+                    // not go stale just because the operator isn't sitting on Activity or
+                    // Statements: top those up too whenever they weren't the tab we just
+                    // polled for the UI.
+                    if current_tab != Tab::Activity {
+                        refresh_metrics_only(
+                            &mut client, dsn, sslmode, sslrootcert, registry, top_n,
+                            Tab::Activity,
+                        );
+                    }
+                    if current_tab != Tab::Statements {
+                        refresh_metrics_only(
+                            &mut client, dsn, sslmode, sslrootcert, registry, top_n,
+                            Tab::Statements,
+                        );
+                    }
+                }
+                if updates.send(update).is_err() {
+                    return;
+                }
+            }
+            Err(e) => {
+                client = None;
+                consecutive_failures = consecutive_failures.saturating_add(1);
+                if updates.send(Update::Error(e.to_string())).is_err() {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Independently refreshes the metrics registry for `tab` when it wasn't the tab the UI
+/// polled this tick, so `/metrics` reflects live Postgres state rather than whatever the
+/// operator happened to have on screen. Best-effort: a failure here drops the connection
+/// like any `fetch_update` error, but doesn't count toward the main loop's backoff and
+/// isn't surfaced to the UI.
+fn refresh_metrics_only(
+    client: &mut Option<PgClient>,
+    dsn: &str,
+    sslmode: SslMode,
+    sslrootcert: Option<&str>,
+    registry: &Arc<Mutex<Metrics>>,
+    top_n: u32,
+    tab: Tab,
+) {
+    if let Ok(update) = fetch_update(client, dsn, sslmode, sslrootcert, tab) {
+        crate::metrics::apply(registry, top_n, tab, &update);
+    }
+}
+
+/// Connects if needed, fetches data for `tab`, and tears the connection down on error so
+/// the next call reconnects from scratch.
+fn fetch_update(
+    client: &mut Option<PgClient>,
+    dsn: &str,
+    sslmode: SslMode,
+    sslrootcert: Option<&str>,
+    tab: Tab,
+) -> Result<Update> {
+    if client.is_none() {
+        *client = Some(PgClient::new(dsn, sslmode, sslrootcert)?);
+    }
+    let c = client.as_mut().expect("just connected");
+
+    let result = (|| -> Result<Update> {
+        Ok(match tab {
+            Tab::Activity => {
+                let conn_by_state = c.fetch_conn_stats()?;
+                let active_queries = c.fetch_active_queries()?;
+                let (cache_hit_pct, total_commits, total_rollbacks, total_backends, max_connections) =
+                    c.fetch_perf_stats()?;
+                let wait_events = c.fetch_wait_events()?;
+                Update::Activity(ActivitySnapshot {
+                    conn_by_state,
+                    active_queries,
+                    cache_hit_pct,
+                    total_commits,
+                    total_rollbacks,
+                    total_backends,
+                    max_connections,
+                    wait_events,
+                })
+            }
+            Tab::Database => {
+                let databases = c.fetch_database_stats()?;
+                let current_db = c.fetch_current_database()?;
+                let relations = c.fetch_relations()?;
+                Update::Database(DatabaseSnapshot {
+                    databases,
+                    current_db,
+                    relations,
+                })
+            }
+            Tab::Locks => Update::Table(c.fetch_locks()?),
+            Tab::IO => Update::Table(c.fetch_io_stats()?),
+            Tab::Statements => Update::Table(c.fetch_statements()?),
+            // Events are pushed by the separate LISTEN/NOTIFY worker, not polled here.
+            Tab::Events => Update::Table(Vec::new()),
+        })
+    })();
+
+    if result.is_err() {
+        *client = None;
+    }
+    result
+}
+
+/// Connects if needed and runs `EXPLAIN` for `query`, tearing the connection down on error
+/// exactly like `fetch_update` so the next regular poll reconnects from scratch.
+fn run_explain(
+    client: &mut Option<PgClient>,
+    dsn: &str,
+    sslmode: SslMode,
+    sslrootcert: Option<&str>,
+    query: &str,
+) -> Result<String> {
+    if client.is_none() {
+        *client = Some(PgClient::new(dsn, sslmode, sslrootcert)?);
+    }
+    let c = client.as_mut().expect("just connected");
+    let result = c.fetch_explain(query);
+    if result.is_err() {
+        *client = None;
+    }
+    result
+}
+
+/// Runs its own single-threaded Tokio runtime to hold an async `tokio-postgres` connection
+/// subscribed to `channels`, forwarding each `NOTIFY` as an `Update::Notification`. This
+/// complements `poll_worker`'s fixed-interval fetches with a true push path.
+fn listen_worker(
+    dsn: &str,
+    sslmode: SslMode,
+    sslrootcert: Option<&str>,
+    channels: &[String],
+    updates: &mpsc::Sender<Update>,
+) {
+    let runtime = match tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+    {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            let _ = updates.send(Update::Error(e.to_string()));
+            return;
+        }
+    };
+
+    if let Err(e) = runtime.block_on(listen(dsn, sslmode, sslrootcert, channels, updates)) {
+        let _ = updates.send(Update::Error(format!("listen worker: {e}")));
+    }
+}
+
+async fn listen(
+    dsn: &str,
+    sslmode: SslMode,
+    sslrootcert: Option<&str>,
+    channels: &[String],
+    updates: &mpsc::Sender<Update>,
+) -> Result<()> {
+    let (client, mut connection) = if sslmode == SslMode::Disable {
+        tokio_postgres::connect(dsn, tokio_postgres::NoTls).await?
+    } else {
+        let mut config: tokio_postgres::Config = dsn
+            .parse()
+            .with_context(|| format!("Failed to parse DSN: {dsn}"))?;
+        config.ssl_mode(crate::pg::client::negotiation_mode(sslmode));
+        let connector = crate::pg::client::build_connector(sslmode, sslrootcert)?;
+        config.connect(connector).await?
+    };
+
+    for channel in channels {
+        client
+            .batch_execute(&format!("LISTEN \"{channel}\""))
+            .await?;
+    }
+
+    loop {
+        match std::future::poll_fn(|cx| connection.poll_message(cx)).await {
+            Some(Ok(tokio_postgres::AsyncMessage::Notification(n))) => {
+                let event = EventRecord {
+                    received_at: chrono::Utc::now().to_rfc3339(),
+                    pid: n.process_id(),
+                    channel: n.channel().to_string(),
+                    payload: n.payload().to_string(),
+                };
+                if updates.send(Update::Notification(event)).is_err() {
+                    return Ok(());
+                }
+            }
+            Some(Ok(_)) => {}
+            Some(Err(e)) => return Err(e.into()),
+            None => return Ok(()),
         }
-        Ok(())
     }
 }
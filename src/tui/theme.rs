@@ -0,0 +1,65 @@
+use ratatui::style::Color;
+use serde::Deserialize;
+use std::str::FromStr;
+
+/// Named-color overrides for the status colors hardcoded across `draw_conn_chart`,
+/// `conn_state_color`, `wait_event_color`, and the cache-hit/connection gauges in
+/// `draw_stats_panel`, plus the percentage thresholds that pick among them. Loaded from
+/// `--config`'s `[theme]` table; any color ratatui's `Color: FromStr` accepts (`"red"`,
+/// `"light-red"`, `"#ff8800"`, ...) is valid, and anything it doesn't recognize falls back
+/// to the built-in default rather than failing startup.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    pub idle: String,
+    pub active: String,
+    pub warn: String,
+    pub crit: String,
+    pub accent: String,
+    pub cache_hit_good_pct: f64,
+    pub cache_hit_ok_pct: f64,
+    pub conn_saturation_ok_pct: f64,
+    pub conn_saturation_warn_pct: f64,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            idle: "cyan".into(),
+            active: "green".into(),
+            warn: "yellow".into(),
+            crit: "red".into(),
+            accent: "cyan".into(),
+            cache_hit_good_pct: 99.0,
+            cache_hit_ok_pct: 95.0,
+            conn_saturation_ok_pct: 70.0,
+            conn_saturation_warn_pct: 90.0,
+        }
+    }
+}
+
+impl Theme {
+    fn resolve(name: &str, fallback: Color) -> Color {
+        Color::from_str(name).unwrap_or(fallback)
+    }
+
+    pub fn idle_color(&self) -> Color {
+        Self::resolve(&self.idle, Color::Cyan)
+    }
+
+    pub fn active_color(&self) -> Color {
+        Self::resolve(&self.active, Color::Green)
+    }
+
+    pub fn warn_color(&self) -> Color {
+        Self::resolve(&self.warn, Color::Yellow)
+    }
+
+    pub fn crit_color(&self) -> Color {
+        Self::resolve(&self.crit, Color::Red)
+    }
+
+    pub fn accent_color(&self) -> Color {
+        Self::resolve(&self.accent, Color::Cyan)
+    }
+}
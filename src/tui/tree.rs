@@ -0,0 +1,196 @@
+use std::collections::HashSet;
+
+/// What a [`TreeNode`] represents in the Database tab's sidebar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NodeKind {
+    Database,
+    Schema,
+    Relation,
+}
+
+/// One flattened row of the Database tab's tree sidebar, in document order.
+#[derive(Clone, Debug)]
+pub struct TreeNode {
+    pub kind: NodeKind,
+    /// Stable identity for this node, used as the key in `App::tree_collapsed`.
+    pub key: String,
+    pub label: String,
+    pub indent: u8,
+    /// Whether this node's descendants are currently hidden (only meaningful for
+    /// `Database`/`Schema` nodes).
+    pub collapsed: bool,
+    /// Whether this node should be rendered, i.e. no collapsed ancestor hides it.
+    pub visible: bool,
+    /// Raw stat columns for the right-hand detail panel (empty for `Schema` nodes).
+    pub stats: Vec<String>,
+}
+
+/// Builds the Database tab's tree: one node per database from `pg_stat_database`
+/// (`databases`), with the connected database (`current_db`) expanded into its schemas and
+/// relations (`relations`, from `pg_stat_user_tables`). Other databases stay leaf nodes,
+/// since their schemas can't be introspected over this single connection. `collapsed_keys`
+/// holds the keys of nodes the user has collapsed; a node's `visible` flag is then derived
+/// by walking the tree and hiding anything under a collapsed ancestor.
+pub fn build_tree(
+    databases: &[Vec<String>],
+    current_db: &str,
+    relations: &[Vec<String>],
+    collapsed_keys: &HashSet<String>,
+) -> Vec<TreeNode> {
+    let mut nodes = Vec::new();
+
+    for db_row in databases {
+        let Some(db_name) = db_row.first() else {
+            continue;
+        };
+        let db_key = format!("db:{db_name}");
+        let db_collapsed = collapsed_keys.contains(&db_key);
+
+        nodes.push(TreeNode {
+            kind: NodeKind::Database,
+            key: db_key.clone(),
+            label: db_name.clone(),
+            indent: 0,
+            collapsed: db_collapsed,
+            visible: true,
+            stats: db_row.clone(),
+        });
+
+        if db_name != current_db {
+            continue;
+        }
+
+        let mut schemas: Vec<&str> = relations
+            .iter()
+            .filter_map(|row| row.first())
+            .map(String::as_str)
+            .collect();
+        schemas.sort_unstable();
+        schemas.dedup();
+
+        for schema in schemas {
+            let schema_key = format!("{db_key}/schema:{schema}");
+            let schema_collapsed = collapsed_keys.contains(&schema_key);
+
+            nodes.push(TreeNode {
+                kind: NodeKind::Schema,
+                key: schema_key.clone(),
+                label: schema.to_string(),
+                indent: 1,
+                collapsed: schema_collapsed,
+                visible: !db_collapsed,
+                stats: Vec::new(),
+            });
+
+            for rel_row in relations
+                .iter()
+                .filter(|row| row.first().map(String::as_str) == Some(schema))
+            {
+                let Some(rel_name) = rel_row.get(1) else {
+                    continue;
+                };
+                nodes.push(TreeNode {
+                    kind: NodeKind::Relation,
+                    key: format!("{schema_key}/rel:{rel_name}"),
+                    label: rel_name.clone(),
+                    indent: 2,
+                    collapsed: false,
+                    visible: !db_collapsed && !schema_collapsed,
+                    stats: rel_row.clone(),
+                });
+            }
+        }
+    }
+
+    nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn databases() -> Vec<Vec<String>> {
+        vec![vec!["app".to_string()], vec!["other".to_string()]]
+    }
+
+    fn relations() -> Vec<Vec<String>> {
+        vec![
+            vec!["public".to_string(), "users".to_string()],
+            vec!["public".to_string(), "orders".to_string()],
+            vec!["audit".to_string(), "log".to_string()],
+        ]
+    }
+
+    #[test]
+    fn test_build_tree_expands_only_current_db() {
+        let nodes = build_tree(&databases(), "app", &relations(), &HashSet::new());
+
+        let other = nodes
+            .iter()
+            .find(|n| n.label == "other")
+            .expect("other db node");
+        assert_eq!(other.kind, NodeKind::Database);
+        assert!(
+            !nodes
+                .iter()
+                .any(|n| n.kind != NodeKind::Database && n.label == "other"),
+            "unconnected databases should stay leaf nodes"
+        );
+
+        let schema_labels: Vec<&str> = nodes
+            .iter()
+            .filter(|n| n.kind == NodeKind::Schema)
+            .map(|n| n.label.as_str())
+            .collect();
+        assert_eq!(schema_labels, vec!["audit", "public"]);
+    }
+
+    #[test]
+    fn test_build_tree_all_visible_when_nothing_collapsed() {
+        let nodes = build_tree(&databases(), "app", &relations(), &HashSet::new());
+        assert!(nodes.iter().all(|n| n.visible));
+    }
+
+    #[test]
+    fn test_build_tree_collapsed_database_hides_schemas_and_relations() {
+        let mut collapsed = HashSet::new();
+        collapsed.insert("db:app".to_string());
+
+        let nodes = build_tree(&databases(), "app", &relations(), &collapsed);
+
+        let db_node = nodes.iter().find(|n| n.key == "db:app").unwrap();
+        assert!(db_node.collapsed);
+        assert!(db_node.visible, "the collapsed node itself stays visible");
+
+        assert!(
+            nodes
+                .iter()
+                .filter(|n| n.kind != NodeKind::Database)
+                .all(|n| !n.visible),
+            "schemas and relations under a collapsed database must be hidden"
+        );
+    }
+
+    #[test]
+    fn test_build_tree_collapsed_schema_hides_only_its_relations() {
+        let mut collapsed = HashSet::new();
+        collapsed.insert("db:app/schema:public".to_string());
+
+        let nodes = build_tree(&databases(), "app", &relations(), &collapsed);
+
+        let public_relations_visible = nodes
+            .iter()
+            .filter(|n| n.kind == NodeKind::Relation && n.key.contains("schema:public"))
+            .all(|n| n.visible);
+        assert!(!public_relations_visible);
+
+        let audit_relations_visible = nodes
+            .iter()
+            .filter(|n| n.kind == NodeKind::Relation && n.key.contains("schema:audit"))
+            .all(|n| n.visible);
+        assert!(
+            audit_relations_visible,
+            "collapsing one schema must not affect siblings"
+        );
+    }
+}
@@ -1,11 +1,17 @@
 use crate::tui::app::{App, Tab};
+use crate::tui::theme::Theme;
+use crate::tui::tree::{NodeKind, TreeNode};
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     symbols,
     text::{Line, Span},
-    widgets::{Axis, Block, Borders, Cell, Chart, Dataset, GraphType, Paragraph, Row, Table, Tabs},
+    widgets::{
+        Axis, Bar, BarChart, BarGroup, Block, Borders, Cell, Chart, Clear, Dataset, Gauge,
+        GraphType, Paragraph, Row, Scrollbar, ScrollbarOrientation, ScrollbarState, Sparkline,
+        Table, Tabs, Wrap,
+    },
 };
 
 pub fn draw(f: &mut Frame, app: &mut App) {
@@ -23,16 +29,115 @@ pub fn draw(f: &mut Frame, app: &mut App) {
         (chunks.first(), chunks.get(1), chunks.get(2))
     {
         draw_tabs(f, app, *tabs_area);
-        if app.current_tab == Tab::Activity {
-            draw_dashboard(f, app, *content);
-        } else {
-            draw_table(f, app, *content);
+        match app.current_tab {
+            Tab::Activity => draw_dashboard(f, app, *content),
+            Tab::Events => draw_events(f, app, *content),
+            Tab::Statements if app.show_detail => draw_statement_detail(f, app, *content),
+            Tab::Database => draw_database_tree(f, app, *content),
+            _ => draw_table(f, app, *content),
         }
         draw_footer(f, app, *footer);
     }
+
+    if app.show_help {
+        draw_help(f, app, f.area());
+    }
+}
+
+/// Splits `area` into a centered `percent_x` x `percent_y` rect, for popups/overlays.
+fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(area);
+
+    let Some(middle) = vertical.get(1) else {
+        return area;
+    };
+
+    let horizontal = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(*middle);
+
+    horizontal.get(1).copied().unwrap_or(area)
+}
+
+/// Centered keybinding reference, grouped by view, toggled with `?`.
+fn draw_help(f: &mut Frame, app: &App, area: Rect) {
+    let popup = centered_rect(60, 70, area);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            "Global",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::raw("  q          Quit"),
+        Line::raw("  ?          Toggle this help"),
+        Line::raw("  1-6        Switch tab"),
+        Line::raw("  ↑/↓        Navigate rows"),
+        Line::raw("  PgUp/PgDn  Scroll a page of rows"),
+        Line::raw("  Home/End   Jump to the first/last row"),
+        Line::raw(""),
+        Line::from(Span::styled(
+            "Activity",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::raw("  ↑/↓        Select an active query (shown in footer)"),
+        Line::raw(""),
+        Line::from(Span::styled(
+            "Database",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::raw("  ←/→        Collapse/expand the selected database or schema"),
+        Line::raw("  Enter      Drill the right panel into the selected relation's stats"),
+        Line::raw(""),
+        Line::from(Span::styled(
+            "Statements",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::raw("  Enter      Open/close EXPLAIN + delta detail for selected_row"),
+        Line::raw("  Esc        Close detail"),
+        Line::raw(""),
+        Line::from(Span::styled(
+            "Events",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::raw("  (streams NOTIFY payloads from --listen channels)"),
+    ];
+    lines.push(Line::raw(""));
+    lines.push(Line::raw(format!("current view: {:?}", app.current_tab)));
+
+    f.render_widget(Clear, popup);
+    let help = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Keybindings (? to close) "),
+        )
+        .wrap(Wrap { trim: false });
+    f.render_widget(help, popup);
 }
 
-fn draw_dashboard(f: &mut Frame, app: &App, area: Rect) {
+fn draw_dashboard(f: &mut Frame, app: &mut App, area: Rect) {
     let rows = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -44,12 +149,19 @@ fn draw_dashboard(f: &mut Frame, app: &App, area: Rect) {
     if let (Some(top), Some(bottom)) = (rows.first(), rows.get(1)) {
         let panels = Layout::default()
             .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .constraints([
+                Constraint::Percentage(50),
+                Constraint::Percentage(25),
+                Constraint::Percentage(25),
+            ])
             .split(*top);
 
-        if let (Some(left), Some(right)) = (panels.first(), panels.get(1)) {
+        if let (Some(left), Some(mid), Some(right)) =
+            (panels.first(), panels.get(1), panels.get(2))
+        {
             draw_conn_chart(f, app, *left);
-            draw_stats_panel(f, app, *right);
+            draw_stats_panel(f, app, *mid);
+            draw_bar_charts(f, app, *right);
         }
         draw_active_queries_panel(f, app, *bottom);
     }
@@ -61,6 +173,7 @@ fn draw_dashboard(f: &mut Frame, app: &App, area: Rect) {
     clippy::cast_sign_loss
 )]
 fn draw_conn_chart(f: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.theme;
     let history = &app.dashboard.conn_history;
 
     // Use the full chart width (minus borders) as the number of data points
@@ -80,18 +193,18 @@ fn draw_conn_chart(f: &mut Frame, app: &App, area: Rect) {
     let max_y = (observed_max as f64 * 1.1).ceil();
     let x_max = chart_width.max(1) as f64;
 
-    // idle severity: green if low, yellow if >50%, red if >80%
+    // idle severity: active-colored if low, warn if >50%, crit if >80%
     let idle_pct = if total_now > 0 {
         idle_now * 100 / total_now
     } else {
         0
     };
     let idle_color = if idle_pct > 80 {
-        Color::Red
+        theme.crit_color()
     } else if idle_pct > 50 {
-        Color::Yellow
+        theme.warn_color()
     } else {
-        Color::Green
+        theme.active_color()
     };
 
     let datasets = vec![
@@ -99,7 +212,7 @@ fn draw_conn_chart(f: &mut Frame, app: &App, area: Rect) {
             .name("connected")
             .marker(symbols::Marker::HalfBlock)
             .graph_type(GraphType::Line)
-            .style(Style::default().fg(Color::Cyan))
+            .style(Style::default().fg(theme.accent_color()))
             .data(&total_data),
         Dataset::default()
             .name("idle")
@@ -117,11 +230,11 @@ fn draw_conn_chart(f: &mut Frame, app: &App, area: Rect) {
                 .borders(Borders::ALL)
                 .title(Line::from(vec![
                     Span::raw(" "),
-                    Span::styled("━", Style::default().fg(Color::Cyan)),
+                    Span::styled("━", Style::default().fg(theme.accent_color())),
                     Span::styled(
                         format!(" connected: {total_now}  "),
                         Style::default()
-                            .fg(Color::Cyan)
+                            .fg(theme.accent_color())
                             .add_modifier(Modifier::BOLD),
                     ),
                     Span::styled("━", Style::default().fg(idle_color)),
@@ -186,14 +299,168 @@ where
         .collect()
 }
 
+#[allow(clippy::cast_precision_loss, clippy::cast_sign_loss)]
 fn draw_stats_panel(f: &mut Frame, app: &App, area: Rect) {
-    let mut lines = conn_state_lines(app);
-    lines.push(Line::raw(""));
-    lines.extend(perf_lines(app));
+    let theme = &app.theme;
+    let block = Block::default().borders(Borders::ALL).title(" Stats ");
+    let inner = block.inner(area);
+    f.render_widget(block, area);
 
-    let widget =
-        Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(" Stats "));
-    f.render_widget(widget, area);
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(6),    // connection state breakdown
+            Constraint::Length(1), // cache-hit gauge
+            Constraint::Length(1), // connection saturation gauge
+            Constraint::Length(1), // commits/tick label
+            Constraint::Length(1), // commits/tick sparkline
+            Constraint::Length(1), // rollbacks/tick label
+            Constraint::Length(1), // rollbacks/tick sparkline
+        ])
+        .split(inner);
+
+    let (
+        Some(&conn_area),
+        Some(&cache_area),
+        Some(&saturation_area),
+        Some(&commits_label),
+        Some(&commits_area),
+        Some(&rollbacks_label),
+        Some(&rollbacks_area),
+    ) = (
+        rows.first(),
+        rows.get(1),
+        rows.get(2),
+        rows.get(3),
+        rows.get(4),
+        rows.get(5),
+        rows.get(6),
+    )
+    else {
+        return;
+    };
+
+    f.render_widget(Paragraph::new(conn_state_lines(app)), conn_area);
+
+    let cache_color = if app.dashboard.cache_hit_pct >= theme.cache_hit_good_pct {
+        theme.active_color()
+    } else if app.dashboard.cache_hit_pct >= theme.cache_hit_ok_pct {
+        theme.warn_color()
+    } else {
+        theme.crit_color()
+    };
+    let cache_ratio = (app.dashboard.cache_hit_pct / 100.0).clamp(0.0, 1.0);
+    f.render_widget(
+        Gauge::default()
+            .gauge_style(Style::default().fg(cache_color))
+            .label(format!("cache hit {:.1}%", app.dashboard.cache_hit_pct))
+            .ratio(cache_ratio),
+        cache_area,
+    );
+
+    let conn_ratio = if app.dashboard.max_connections > 0 {
+        app.dashboard.total_backends as f64 / app.dashboard.max_connections as f64
+    } else {
+        0.0
+    };
+    let conn_pct = conn_ratio * 100.0;
+    let conn_color = if conn_pct < theme.conn_saturation_ok_pct {
+        theme.active_color()
+    } else if conn_pct < theme.conn_saturation_warn_pct {
+        theme.warn_color()
+    } else {
+        theme.crit_color()
+    };
+    f.render_widget(
+        Gauge::default()
+            .gauge_style(Style::default().fg(conn_color))
+            .label(format!(
+                "conns {} / {}",
+                app.dashboard.total_backends, app.dashboard.max_connections
+            ))
+            .ratio(conn_ratio.clamp(0.0, 1.0)),
+        saturation_area,
+    );
+
+    f.render_widget(
+        Paragraph::new(format!(
+            "  commits/tick ({})",
+            app.dashboard.commits_history.back().copied().unwrap_or(0)
+        )),
+        commits_label,
+    );
+    let commits_data: Vec<u64> = app
+        .dashboard
+        .commits_history
+        .iter()
+        .map(|&v| v.max(0) as u64)
+        .collect();
+    f.render_widget(
+        Sparkline::default()
+            .data(&commits_data)
+            .style(Style::default().fg(theme.accent_color())),
+        commits_area,
+    );
+
+    f.render_widget(
+        Paragraph::new(format!(
+            "  rollbacks/tick ({})",
+            app.dashboard
+                .rollbacks_history
+                .back()
+                .copied()
+                .unwrap_or(0)
+        )),
+        rollbacks_label,
+    );
+    let rollbacks_data: Vec<u64> = app
+        .dashboard
+        .rollbacks_history
+        .iter()
+        .map(|&v| v.max(0) as u64)
+        .collect();
+    f.render_widget(
+        Sparkline::default()
+            .data(&rollbacks_data)
+            .style(Style::default().fg(theme.warn_color())),
+        rollbacks_area,
+    );
+}
+
+/// Color for a `pg_stat_activity.state` value, shared between the text breakdown and the
+/// connections `BarChart`.
+fn conn_state_color(state: &str, theme: &Theme) -> Color {
+    match state {
+        "active" => theme.active_color(),
+        "idle in transaction" => theme.warn_color(),
+        "idle in transaction (aborted)" => theme.crit_color(),
+        "idle" => theme.idle_color(),
+        _ => Color::Gray,
+    }
+}
+
+/// Shortened display label for a `pg_stat_activity.state` value.
+fn conn_state_label(state: &str) -> &str {
+    match state {
+        "active" => "active",
+        "idle" => "idle",
+        "idle in transaction" => "idle in tx",
+        "idle in transaction (aborted)" => "idle in tx (abort)",
+        "fastpath function call" => "fastpath",
+        "disabled" => "disabled",
+        other => other,
+    }
+}
+
+/// Color for a `"{wait_event_type}:{wait_event}"` label, grouped roughly by whether it
+/// indicates CPU-bound (`none`), lock-bound, or IO-bound work.
+fn wait_event_color(label: &str, theme: &Theme) -> Color {
+    match label.split(':').next().unwrap_or(label) {
+        "none" => theme.active_color(),
+        "Lock" => theme.crit_color(),
+        "IO" => theme.warn_color(),
+        _ => theme.accent_color(),
+    }
 }
 
 fn conn_state_lines(app: &App) -> Vec<Line<'static>> {
@@ -204,22 +471,8 @@ fn conn_state_lines(app: &App) -> Vec<Line<'static>> {
         .conn_by_state
         .iter()
         .map(|(state, count)| {
-            let color = match state.as_str() {
-                "active" => Color::Green,
-                "idle in transaction" => Color::Yellow,
-                "idle in transaction (aborted)" => Color::Red,
-                "idle" => Color::Cyan,
-                _ => Color::Gray,
-            };
-            let label = match state.as_str() {
-                "active" => "active",
-                "idle" => "idle",
-                "idle in transaction" => "idle in tx",
-                "idle in transaction (aborted)" => "idle in tx (abort)",
-                "fastpath function call" => "fastpath",
-                "disabled" => "disabled",
-                other => other,
-            };
+            let color = conn_state_color(state, &app.theme);
+            let label = conn_state_label(state);
             Line::from(vec![
                 Span::raw(format!("  {label:<20}")),
                 Span::styled(
@@ -243,69 +496,82 @@ fn conn_state_lines(app: &App) -> Vec<Line<'static>> {
     lines
 }
 
-fn perf_lines(app: &App) -> Vec<Line<'static>> {
-    let cache_color = if app.dashboard.cache_hit_pct >= 99.0 {
-        Color::Green
-    } else if app.dashboard.cache_hit_pct >= 95.0 {
-        Color::Yellow
-    } else {
-        Color::Red
-    };
-    let conn_pct = if app.dashboard.max_connections > 0 {
-        app.dashboard.total_backends * 100 / app.dashboard.max_connections
-    } else {
-        0
-    };
-    let conn_color = if conn_pct < 70 {
-        Color::Green
-    } else if conn_pct < 90 {
-        Color::Yellow
-    } else {
-        Color::Red
-    };
+/// Splits `area` into a connection-state `BarChart` on top and a top-wait-events
+/// `BarChart` below, so operators can see at a glance whether load is CPU, lock, or IO
+/// bound without reading the numeric breakdown.
+fn draw_bar_charts(f: &mut Frame, app: &App, area: Rect) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
 
-    vec![
-        Line::from(vec![
-            Span::raw(format!("  {:<20}", "cache hit")),
-            Span::styled(
-                format!("{:.1}%", app.dashboard.cache_hit_pct),
-                Style::default()
-                    .fg(cache_color)
-                    .add_modifier(Modifier::BOLD),
-            ),
-        ]),
-        Line::from(vec![
-            Span::raw(format!("  {:<20}", "commits")),
-            Span::styled(
-                format!("{}", app.dashboard.total_commits),
-                Style::default().fg(Color::White),
-            ),
-        ]),
-        Line::from(vec![
-            Span::raw(format!("  {:<20}", "rollbacks")),
-            Span::styled(
-                format!("{}", app.dashboard.total_rollbacks),
-                Style::default().fg(if app.dashboard.total_rollbacks > 0 {
-                    Color::Yellow
-                } else {
-                    Color::Gray
-                }),
-            ),
-        ]),
-        Line::from(vec![
-            Span::raw(format!("  {:<20}", "max conns")),
-            Span::styled(
-                format!(
-                    "{} / {} ({}%)",
-                    app.dashboard.total_backends, app.dashboard.max_connections, conn_pct
-                ),
-                Style::default().fg(conn_color).add_modifier(Modifier::BOLD),
-            ),
-        ]),
-    ]
+    if let (Some(top), Some(bottom)) = (rows.first(), rows.get(1)) {
+        draw_conn_bar_chart(f, app, *top);
+        draw_wait_events_bar_chart(f, app, *bottom);
+    }
+}
+
+fn draw_conn_bar_chart(f: &mut Frame, app: &App, area: Rect) {
+    let bars: Vec<Bar> = app
+        .dashboard
+        .conn_by_state
+        .iter()
+        .map(|(state, count)| {
+            let color = conn_state_color(state, &app.theme);
+            Bar::default()
+                .label(Line::from(conn_state_label(state)))
+                .value(u64::try_from(*count).unwrap_or(0))
+                .style(Style::default().fg(color))
+                .value_style(
+                    Style::default()
+                        .fg(Color::Black)
+                        .bg(color)
+                        .add_modifier(Modifier::BOLD),
+                )
+        })
+        .collect();
+
+    let chart = BarChart::default()
+        .block(Block::default().borders(Borders::ALL).title(" Connections "))
+        .bar_width(6)
+        .bar_gap(1)
+        .data(BarGroup::default().bars(&bars));
+    f.render_widget(chart, area);
+}
+
+fn draw_wait_events_bar_chart(f: &mut Frame, app: &App, area: Rect) {
+    let bars: Vec<Bar> = app
+        .dashboard
+        .wait_events
+        .iter()
+        .map(|(label, count)| {
+            let color = wait_event_color(label, &app.theme);
+            Bar::default()
+                .label(Line::from(label.as_str()))
+                .value(u64::try_from(*count).unwrap_or(0))
+                .style(Style::default().fg(color))
+                .value_style(
+                    Style::default()
+                        .fg(Color::Black)
+                        .bg(color)
+                        .add_modifier(Modifier::BOLD),
+                )
+        })
+        .collect();
+
+    let chart = BarChart::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Wait Events "),
+        )
+        .bar_width(6)
+        .bar_gap(1)
+        .data(BarGroup::default().bars(&bars));
+    f.render_widget(chart, area);
 }
 
-fn draw_active_queries_panel(f: &mut Frame, app: &App, area: Rect) {
+fn draw_active_queries_panel(f: &mut Frame, app: &mut App, area: Rect) {
     let header_cells = ["PID", "User", "DB", "Duration", "Query"];
     let widths = [
         Constraint::Length(8),
@@ -319,18 +585,74 @@ fn draw_active_queries_panel(f: &mut Frame, app: &App, area: Rect) {
         .dashboard
         .active_queries
         .iter()
+        .map(|items| Row::new(items.iter().map(|c| Cell::from(c.as_str()))).style(Style::default().fg(Color::Green)));
+
+    let count = app.dashboard.active_queries.len();
+    let title = format!(" Active Queries ({count}) | top-n: {} ", app.top_n);
+    let table = Table::new(rows, widths)
+        .header(
+            Row::new(header_cells.iter().map(|h| Cell::from(*h)))
+                .style(
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                )
+                .bottom_margin(1),
+        )
+        .highlight_style(Style::default().fg(Color::Black).bg(Color::White))
+        .block(Block::default().borders(Borders::ALL).title(title));
+
+    app.table_area = area;
+    app.table_state
+        .select((count > 0).then(|| app.selected_row.min(count - 1)));
+    f.render_stateful_widget(table, area, &mut app.table_state);
+    render_scrollbar(f, area, count, app.selected_row);
+}
+
+/// Vertical scrollbar on the right edge of `area` reflecting `selected` out of `total`
+/// rows, mirroring the `Table`'s own scroll position for large result sets.
+fn render_scrollbar(f: &mut Frame, area: Rect, total: usize, selected: usize) {
+    if total == 0 {
+        return;
+    }
+    let mut state = ScrollbarState::new(total).position(selected);
+    let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+        .begin_symbol(None)
+        .end_symbol(None);
+    f.render_stateful_widget(scrollbar, area, &mut state);
+}
+
+fn draw_events(f: &mut Frame, app: &App, area: Rect) {
+    let header_cells = ["Time", "PID", "Channel", "Payload"];
+    let widths = [
+        Constraint::Length(25),
+        Constraint::Length(8),
+        Constraint::Length(20),
+        Constraint::Min(20),
+    ];
+
+    let rows = app
+        .events
+        .iter()
         .enumerate()
-        .map(|(i, items)| {
+        .map(|(i, event)| {
             let style = if i == app.selected_row {
                 Style::default().fg(Color::Black).bg(Color::White)
             } else {
-                Style::default().fg(Color::Green)
+                Style::default().fg(Color::Magenta)
             };
-            Row::new(items.iter().map(|c| Cell::from(c.as_str()))).style(style)
-        });
+            Row::new(vec![
+                Cell::from(event.received_at.as_str()),
+                Cell::from(event.pid.to_string()),
+                Cell::from(event.channel.as_str()),
+                Cell::from(event.payload.as_str()),
+            ])
+            .style(style)
+        })
+        .collect::<Vec<_>>();
 
-    let count = app.dashboard.active_queries.len();
-    let title = format!(" Active Queries ({count}) | top-n: {} ", app.top_n);
+    let count = app.events.len();
+    let title = format!(" Events ({count}) | listening on NOTIFY ");
     let table = Table::new(rows, widths)
         .header(
             Row::new(header_cells.iter().map(|h| Cell::from(*h)))
@@ -345,21 +667,22 @@ fn draw_active_queries_panel(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(table, area);
 }
 
-fn draw_tabs(f: &mut Frame, app: &App, area: Rect) {
-    let titles = vec![
-        "1:Activity",
-        "2:Database",
-        "3:Locks",
-        "4:IO",
-        "5:Statements",
-    ];
-    let selected_tab = match app.current_tab {
-        Tab::Activity => 0,
-        Tab::Database => 1,
-        Tab::Locks => 2,
-        Tab::IO => 3,
-        Tab::Statements => 4,
-    };
+/// Titles in display order, paired with the `Tab` each one switches to.
+const TAB_TITLES: [(&str, Tab); 6] = [
+    ("1:Activity", Tab::Activity),
+    ("2:Database", Tab::Database),
+    ("3:Locks", Tab::Locks),
+    ("4:IO", Tab::IO),
+    ("5:Statements", Tab::Statements),
+    ("6:Events", Tab::Events),
+];
+
+fn draw_tabs(f: &mut Frame, app: &mut App, area: Rect) {
+    let titles: Vec<&str> = TAB_TITLES.iter().map(|(title, _)| *title).collect();
+    let selected_tab = TAB_TITLES
+        .iter()
+        .position(|(_, tab)| *tab == app.current_tab)
+        .unwrap_or(0);
     let tabs = Tabs::new(titles)
         .block(Block::default().borders(Borders::ALL).title("Views"))
         .select(selected_tab)
@@ -370,9 +693,112 @@ fn draw_tabs(f: &mut Frame, app: &App, area: Rect) {
                 .add_modifier(Modifier::BOLD),
         );
     f.render_widget(tabs, area);
+    app.tab_rects = tab_click_rects(area, &TAB_TITLES);
 }
 
-fn draw_table(f: &mut Frame, app: &App, area: Rect) {
+/// Per-tab clickable rects, mirroring the `Tabs` widget's own default layout: a 1-char
+/// border inset, then each title padded by one space on each side and separated by a
+/// 1-char divider. Recorded so mouse clicks can be mapped back to a `Tab` without
+/// re-deriving this layout in the event loop; keep in sync with `draw_tabs` if its
+/// block/padding/divider ever changes.
+#[allow(clippy::cast_possible_truncation)]
+fn tab_click_rects(area: Rect, tabs: &[(&str, Tab)]) -> Vec<(Tab, Rect)> {
+    let inner = Block::default().borders(Borders::ALL).inner(area);
+    let mut x = inner.x;
+    tabs.iter()
+        .map(|(title, tab)| {
+            let width = title.chars().count() as u16 + 2; // 1-space padding on each side
+            let rect = Rect {
+                x,
+                y: inner.y,
+                width,
+                height: 1,
+            };
+            x += width + 1; // + 1-char divider
+            (*tab, rect)
+        })
+        .collect()
+}
+
+/// Database tab: a ~25%-width collapsible Database → Schema → Relation tree on the left
+/// (borrowed from gobang's database-tree sidebar), and either the selected database's
+/// totals or the relation last drilled into with `Enter` on the right.
+fn draw_database_tree(f: &mut Frame, app: &App, area: Rect) {
+    let panels = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(25), Constraint::Percentage(75)])
+        .split(area);
+
+    let (Some(left), Some(right)) = (panels.first(), panels.get(1)) else {
+        return;
+    };
+
+    let visible: Vec<&TreeNode> = app.tree.iter().filter(|n| n.visible).collect();
+
+    let lines: Vec<Line> = visible
+        .iter()
+        .enumerate()
+        .map(|(i, node)| {
+            let marker = match node.kind {
+                NodeKind::Relation => "  ",
+                _ if node.collapsed => "▸ ",
+                _ => "▾ ",
+            };
+            let indent = "  ".repeat(node.indent as usize);
+            let style = if i == app.selected_row {
+                Style::default().fg(Color::Black).bg(Color::White)
+            } else {
+                match node.kind {
+                    NodeKind::Database => Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                    NodeKind::Schema => Style::default().fg(Color::Yellow),
+                    NodeKind::Relation => Style::default(),
+                }
+            };
+            Line::from(Span::styled(format!("{indent}{marker}{}", node.label), style))
+        })
+        .collect();
+
+    let tree_widget =
+        Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(" Databases "));
+    f.render_widget(tree_widget, *left);
+
+    const DB_STAT_LABELS: [&str; 8] = [
+        "DB", "Backends", "Commits", "Rollbacks", "Read", "Hit", "Fetched", "Reset",
+    ];
+    const RELATION_STAT_LABELS: [&str; 6] = [
+        "Schema", "Relation", "Seq Scans", "Index Scans", "Live Tuples", "Dead Tuples",
+    ];
+
+    let detail_lines: Vec<Line> = if let Some(stats) = &app.detail_relation {
+        stats
+            .iter()
+            .zip(RELATION_STAT_LABELS)
+            .map(|(v, label)| Line::from(format!("{label}: {v}")))
+            .collect()
+    } else if let Some(node) = visible.get(app.selected_row) {
+        match node.kind {
+            NodeKind::Database => node
+                .stats
+                .iter()
+                .zip(DB_STAT_LABELS)
+                .map(|(v, label)| Line::from(format!("{label}: {v}")))
+                .collect(),
+            NodeKind::Schema => vec![Line::raw("Select a relation and press Enter for stats")],
+            NodeKind::Relation => vec![Line::raw("Enter: show per-table stats")],
+        }
+    } else {
+        vec![Line::raw("No databases")]
+    };
+
+    let detail = Paragraph::new(detail_lines)
+        .block(Block::default().borders(Borders::ALL).title(" Detail "))
+        .wrap(Wrap { trim: false });
+    f.render_widget(detail, *right);
+}
+
+fn draw_table(f: &mut Frame, app: &mut App, area: Rect) {
     let header_cells = match app.current_tab {
         Tab::Activity => vec![
             "PID", "User", "DB", "State", "Query", "Start", "App", "Client",
@@ -390,6 +816,7 @@ fn draw_table(f: &mut Frame, app: &App, area: Rect) {
         Tab::Locks => vec!["Relation", "Mode", "Granted", "PID"],
         Tab::IO => vec!["Backend", "Read", "Write", "Time Read", "Time Write"],
         Tab::Statements => vec!["Query", "Total", "Mean", "Calls", "Read", "Write"],
+        Tab::Events => unreachable!("Events renders via draw_events, never draw_table"),
     };
 
     let widths = match app.current_tab {
@@ -406,14 +833,11 @@ fn draw_table(f: &mut Frame, app: &App, area: Rect) {
         _ => vec![Constraint::Percentage(20); header_cells.len()],
     };
 
-    let rows = app.data.iter().enumerate().map(|(i, items)| {
-        let style = if i == app.selected_row {
-            Style::default().fg(Color::Black).bg(Color::White)
-        } else {
-            Style::default()
-        };
-        Row::new(items.iter().map(|c| Cell::from(c.as_str()))).style(style)
-    });
+    let count = app.data.len();
+    let rows = app
+        .data
+        .iter()
+        .map(|items| Row::new(items.iter().map(|c| Cell::from(c.as_str()))));
 
     let table = Table::new(rows, widths)
         .header(
@@ -425,32 +849,110 @@ fn draw_table(f: &mut Frame, app: &App, area: Rect) {
                 )
                 .bottom_margin(1),
         )
+        .highlight_style(Style::default().fg(Color::Black).bg(Color::White))
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .title(format!("{:?} View", app.current_tab)),
         );
-    f.render_widget(table, area);
+
+    app.table_area = area;
+    app.table_state
+        .select((count > 0).then(|| app.selected_row.min(count - 1)));
+    f.render_stateful_widget(table, area, &mut app.table_state);
+    render_scrollbar(f, area, count, app.selected_row);
+}
+
+/// Drill-down pane for the row `Enter` was pressed on in the Statements tab: the full
+/// query text and its per-refresh deltas on top, the `EXPLAIN` plan below.
+fn draw_statement_detail(f: &mut Frame, app: &App, area: Rect) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(8), Constraint::Min(0)])
+        .split(area);
+
+    let (Some(top), Some(bottom)) = (rows.first(), rows.get(1)) else {
+        return;
+    };
+
+    let queryid = app
+        .data
+        .get(app.selected_row)
+        .and_then(|row| row.get(6))
+        .map(String::as_str)
+        .unwrap_or("");
+
+    let mut lines = vec![Line::raw(
+        app.detail_query.as_deref().unwrap_or("").to_string(),
+    )];
+    if let Some(stats) = app.statement_stats.get(queryid) {
+        lines.push(Line::raw(""));
+        lines.push(Line::from(vec![
+            Span::raw(format!("calls/sec: {:.2}  ", stats.calls_per_sec)),
+            Span::raw(format!("Δtotal_time: {:.2}ms  ", stats.total_time_delta_ms)),
+            Span::raw(format!("Δmean_time: {:.2}ms", stats.mean_time_delta_ms)),
+        ]));
+    }
+    let top_widget = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title(" Statement "))
+        .wrap(Wrap { trim: false });
+    f.render_widget(top_widget, *top);
+
+    let explain_text = app
+        .detail_explain
+        .as_deref()
+        .unwrap_or("fetching EXPLAIN…");
+    let explain_widget = Paragraph::new(explain_text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" EXPLAIN (FORMAT TEXT) "),
+        )
+        .wrap(Wrap { trim: false });
+    f.render_widget(explain_widget, *bottom);
 }
 
 fn draw_footer(f: &mut Frame, app: &App, area: Rect) {
+    if app.reconnecting {
+        let footer = Paragraph::new(Line::from(vec![Span::styled(
+            "reconnecting to Postgres… | q:Quit",
+            Style::default()
+                .fg(Color::Red)
+                .add_modifier(Modifier::BOLD),
+        )]))
+        .block(Block::default().borders(Borders::ALL));
+        f.render_widget(footer, area);
+        return;
+    }
+
+    if app.show_detail {
+        let footer = Paragraph::new(Line::from(vec![Span::raw(
+            "Esc/Enter:Close detail | q:Quit",
+        )]))
+        .block(Block::default().borders(Borders::ALL));
+        f.render_widget(footer, area);
+        return;
+    }
+
     let footer_text = if app.current_tab == Tab::Activity {
         if let Some(row_data) = app.dashboard.active_queries.get(app.selected_row) {
             let query = row_data.get(4).map_or("", String::as_str);
             format!("QUERY: {}", query.replace('\n', " "))
         } else {
-            "q:Quit | 1-5:Switch Tab | ↑↓:Navigate".to_string()
+            "q:Quit | ?:Help | 1-6:Switch Tab | ↑↓:Navigate".to_string()
         }
+    } else if app.current_tab == Tab::Database {
+        "q:Quit | ?:Help | 1-6:Switch Tab | ↑↓:Navigate | ←→:Collapse/Expand | Enter:Relation detail".to_string()
     } else if let Some(row_data) = app.data.get(app.selected_row) {
         match app.current_tab {
             Tab::Statements => {
                 let query = row_data.first().map_or("", String::as_str);
-                format!("QUERY: {}", query.replace('\n', " "))
+                format!("QUERY: {} | Enter:Detail", query.replace('\n', " "))
             }
-            _ => "q:Quit | 1-5:Switch Tab | ↑↓:Navigate".to_string(),
+            _ => "q:Quit | ?:Help | 1-6:Switch Tab | ↑↓:Navigate".to_string(),
         }
     } else {
-        "q:Quit | 1-5:Switch Tab | ↑↓:Navigate".to_string()
+        "q:Quit | ?:Help | 1-6:Switch Tab | ↑↓:Navigate".to_string()
     };
 
     let footer = Paragraph::new(Line::from(vec![Span::raw(footer_text)]))